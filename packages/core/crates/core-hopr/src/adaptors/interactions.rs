@@ -1,8 +1,130 @@
+use std::sync::Arc;
+
 use futures::channel::mpsc::{channel, unbounded, Sender, UnboundedSender};
 use futures::future::poll_fn;
 
 use core_crypto::types::HalfKeyChallenge;
+use core_types::channels::AcknowledgedTicket;
 use utils_log::error;
+use utils_types::traits::BinarySerializable;
+
+/// Serializes/deserializes a payload of type `T` on its way to or from a host callback.
+///
+/// `spawn_on_final_packet_loop` and `spawn_ack_receiver_loop` used to hard-code `T`'s own byte
+/// representation; a codec lets embedders negotiate a different wire format (e.g. per peer, via
+/// the peer metadata map) without touching the delivery loops themselves.
+pub trait PacketCodec<T> {
+    fn encode(&self, value: &T) -> Vec<u8>;
+    fn decode(&self, bytes: &[u8]) -> Option<T>;
+}
+
+/// The historical wire format: `T`'s own [`BinarySerializable`] bytes, unchanged. Used whenever
+/// no codec is supplied, so existing embedders see no behavior change.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RawCodec;
+
+impl<T: BinarySerializable> PacketCodec<T> for RawCodec {
+    fn encode(&self, value: &T) -> Vec<u8> {
+        value.to_bytes().into()
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Option<T> {
+        T::from_bytes(bytes).ok()
+    }
+}
+
+#[cfg(feature = "codec-msgpack")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MessagePackCodec;
+
+#[cfg(feature = "codec-msgpack")]
+impl<T: serde::Serialize + serde::de::DeserializeOwned> PacketCodec<T> for MessagePackCodec {
+    fn encode(&self, value: &T) -> Vec<u8> {
+        rmp_serde::to_vec(value).unwrap_or_default()
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Option<T> {
+        rmp_serde::from_slice(bytes).ok()
+    }
+}
+
+#[cfg(feature = "codec-bincode")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BincodeCodec;
+
+#[cfg(feature = "codec-bincode")]
+impl<T: serde::Serialize + serde::de::DeserializeOwned> PacketCodec<T> for BincodeCodec {
+    fn encode(&self, value: &T) -> Vec<u8> {
+        bincode::serialize(value).unwrap_or_default()
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Option<T> {
+        bincode::deserialize(bytes).ok()
+    }
+}
+
+#[cfg(feature = "codec-postcard")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PostcardCodec;
+
+#[cfg(feature = "codec-postcard")]
+impl<T: serde::Serialize + serde::de::DeserializeOwned> PacketCodec<T> for PostcardCodec {
+    fn encode(&self, value: &T) -> Vec<u8> {
+        postcard::to_allocvec(value).unwrap_or_default()
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Option<T> {
+        postcard::from_bytes(bytes).ok()
+    }
+}
+
+#[cfg(feature = "codec-json")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonCodec;
+
+#[cfg(feature = "codec-json")]
+impl<T: serde::Serialize + serde::de::DeserializeOwned> PacketCodec<T> for JsonCodec {
+    fn encode(&self, value: &T) -> Vec<u8> {
+        serde_json::to_vec(value).unwrap_or_default()
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Option<T> {
+        serde_json::from_slice(bytes).ok()
+    }
+}
+
+/// Bounded channel size for final-packet delivery, shared by the wasm callback loop and the
+/// native stream below so both backpressure the same way.
+const ON_PACKET_QUEUE_SIZE: usize = 4096;
+
+/// Bounded channel size for acknowledged-ticket delivery, shared by the wasm callback loop and
+/// the native stream below so both backpressure the same way.
+const ON_ACK_TKT_QUEUE_SIZE: usize = 2048;
+
+/// Native counterpart to `wasm::spawn_ack_receiver_loop`, for pure-Rust hosts (and the
+/// forthcoming browser-WS/WebRTC wasm transports) that want to consume acknowledgements as a
+/// `Stream` instead of a JS callback. Backed by the same unbounded channel; returns the sender
+/// the producer side pushes challenges onto, paired with the `Stream` the host polls.
+pub fn ack_challenges() -> (UnboundedSender<HalfKeyChallenge>, impl futures::Stream<Item = HalfKeyChallenge>) {
+    unbounded::<HalfKeyChallenge>()
+}
+
+/// Native counterpart to `wasm::spawn_on_final_packet_loop`, for pure-Rust hosts that want to
+/// consume delivered packets as a `Stream` instead of a JS callback. Backed by the same bounded
+/// channel; returns the sender the producer side pushes packets onto, paired with the `Stream`
+/// the host polls.
+pub fn final_packets(
+) -> (Sender<core_types::protocol::ApplicationData>, impl futures::Stream<Item = core_types::protocol::ApplicationData>) {
+    channel::<core_types::protocol::ApplicationData>(ON_PACKET_QUEUE_SIZE)
+}
+
+/// Native counterpart to `wasm::spawn_ack_tkt_receiver_loop`, for pure-Rust hosts that want to
+/// consume redeemable tickets as a `Stream` instead of a JS callback. Backed by the same bounded
+/// channel; returns the sender the producer side pushes tickets onto, paired with the `Stream`
+/// the host polls.
+pub fn acknowledged_tickets() -> (Sender<AcknowledgedTicket>, impl futures::Stream<Item = AcknowledgedTicket>) {
+    channel::<AcknowledgedTicket>(ON_ACK_TKT_QUEUE_SIZE)
+}
 
 #[cfg(feature = "wasm")]
 pub mod wasm {
@@ -14,18 +136,24 @@ pub mod wasm {
     use futures::Stream;
     use js_sys::Uint8Array;
     use utils_log::debug;
-    use utils_types::traits::BinarySerializable;
     use wasm_bindgen::prelude::*;
 
     /// Helper loop ensuring conversion and enqueueing of events on acknowledgement
-    pub fn spawn_ack_receiver_loop(on_ack: Option<js_sys::Function>) -> Option<UnboundedSender<HalfKeyChallenge>> {
+    pub fn spawn_ack_receiver_loop(
+        on_ack: Option<js_sys::Function>,
+        codec: Option<Arc<dyn PacketCodec<HalfKeyChallenge> + Send + Sync>>,
+    ) -> Option<UnboundedSender<HalfKeyChallenge>> {
+        let codec = codec.unwrap_or_else(|| Arc::new(RawCodec));
+
         match on_ack {
             Some(on_ack_fn) => {
                 let (tx, mut rx) = unbounded::<HalfKeyChallenge>();
 
                 wasm_bindgen_futures::spawn_local(async move {
                     while let Some(ack) = poll_fn(|cx| Pin::new(&mut rx).poll_next(cx)).await {
-                        if let Err(e) = on_ack_fn.call1(&JsValue::null(), &ack.into()) {
+                        let bytes = codec.encode(&ack);
+                        if let Err(e) = on_ack_fn.call1(&JsValue::null(), Uint8Array::from(bytes.as_slice()).as_ref())
+                        {
                             error!("failed to call on_ack closure: {:?}", e.as_string());
                         }
                     }
@@ -38,21 +166,41 @@ pub mod wasm {
     }
 
     /// Helper loop ensuring conversion and enqueueing of events on acknowledgement ticket
-    /*pub fn spawn_ack_tkt_receiver_loop<F>(
-        on_ack_tkt: F
-    ) -> UnboundedSender<AcknowledgedTicket>
-    where F: Fn(&AcknowledgedTicket) -> Pin<Box<dyn Future<Output = ()>>> {
-        let (tx, mut rx) = unbounded::<AcknowledgedTicket>();
+    pub fn spawn_ack_tkt_receiver_loop(
+        on_ack_tkt: Option<js_sys::Function>,
+        codec: Option<Arc<dyn PacketCodec<AcknowledgedTicket> + Send + Sync>>,
+    ) -> Option<Sender<AcknowledgedTicket>> {
+        let codec = codec.unwrap_or_else(|| Arc::new(RawCodec));
 
-        wasm_bindgen_futures::spawn_local();
+        match on_ack_tkt {
+            Some(on_ack_tkt_fn) => {
+                let (tx, mut rx) = channel::<AcknowledgedTicket>(ON_ACK_TKT_QUEUE_SIZE);
 
-        tx
-    }*/
+                wasm_bindgen_futures::spawn_local(async move {
+                    while let Some(ticket) = poll_fn(|cx| Pin::new(&mut rx).poll_next(cx)).await {
+                        debug!("wasm acknowledged ticket loop received a new ticket");
+                        let bytes = codec.encode(&ticket);
+                        if let Err(e) =
+                            on_ack_tkt_fn.call1(&JsValue::null(), Uint8Array::from(bytes.as_slice()).as_ref())
+                        {
+                            error!("failed to call on_ack_tkt closure: {:?}", e.as_string());
+                        }
+                    }
+                });
 
-    const ON_PACKET_QUEUE_SIZE: usize = 4096;
+                Some(tx)
+            }
+            None => None,
+        }
+    }
 
     /// Helper loop ensuring conversion and enqueueing of events on receiving the final packet
-    pub fn spawn_on_final_packet_loop(on_final_packet: Option<js_sys::Function>) -> Option<Sender<ApplicationData>> {
+    pub fn spawn_on_final_packet_loop(
+        on_final_packet: Option<js_sys::Function>,
+        codec: Option<Arc<dyn PacketCodec<ApplicationData> + Send + Sync>>,
+    ) -> Option<Sender<ApplicationData>> {
+        let codec = codec.unwrap_or_else(|| Arc::new(RawCodec));
+
         match on_final_packet {
             Some(on_msg_rcv) => {
                 let (tx, mut rx) = channel::<ApplicationData>(ON_PACKET_QUEUE_SIZE);
@@ -60,8 +208,8 @@ pub mod wasm {
                 wasm_bindgen_futures::spawn_local(async move {
                     while let Some(packet) = poll_fn(|cx| Pin::new(&mut rx).poll_next(cx)).await {
                         debug!("wasm packet interaction loop received a new packet");
-                        if let Err(e) =
-                            on_msg_rcv.call1(&JsValue::null(), Uint8Array::from(packet.to_bytes().as_ref()).as_ref())
+                        let bytes = codec.encode(&packet);
+                        if let Err(e) = on_msg_rcv.call1(&JsValue::null(), Uint8Array::from(bytes.as_slice()).as_ref())
                         {
                             error!("failed to call on_ack_ticket closure: {:?}", e.as_string());
                         }
@@ -1,7 +1,10 @@
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use async_lock::RwLock;
 use async_trait::async_trait;
+use sha3::{Digest, Keccak256};
 
 use core_network::{
     network::Network,
@@ -12,6 +15,156 @@ use core_network::{
 
 use crate::{adaptors::network::ExternalNetworkInteractions, constants::PEER_METADATA_PROTOCOL_VERSION};
 
+/// Metadata key carrying the endpoint-proof verdict (`"true"`/`"false"`) for a finished ping.
+const PEER_METADATA_VERIFIED: &str = "verified";
+
+/// How long a successfully verified endpoint-proof stays valid before a peer has to prove
+/// control of its claimed address again.
+const PING_VERIFICATION_TTL: Duration = Duration::from_secs(600);
+
+#[derive(Debug, Clone, Copy)]
+struct VerifiedEntry {
+    verified_at: Instant,
+    token: [u8; 32],
+}
+
+/// TTL-bounded endpoint-proof state for [`PingExternalInteractions`].
+///
+/// Tracks the last verified instant and token per peer so a fresh pong within
+/// [`PING_VERIFICATION_TTL`] does not need to re-prove address ownership, plus the tokens
+/// handed out for probes that have not yet received (or failed) a matching pong. The verdict is
+/// recorded in the peer's metadata under [`PEER_METADATA_VERIFIED`]; the ping result handed to
+/// `Network` does not depend on it.
+#[derive(Debug, Default)]
+struct PingCache {
+    verified: Mutex<HashMap<PeerId, VerifiedEntry>>,
+    in_flight: Mutex<HashMap<PeerId, [u8; 32]>>,
+}
+
+impl PingCache {
+    /// Generates a fresh CSPRNG challenge token for `peer`, stores it as the in-flight challenge
+    /// (replacing any token already stored for `peer`) and returns it, so it can be embedded in a
+    /// probe and later checked against the pong's echoed proof.
+    fn issue_challenge(&self, peer: &PeerId) -> [u8; 32] {
+        let mut token = [0u8; 32];
+        getrandom::getrandom(&mut token).expect("failed to source randomness for ping challenge");
+        self.in_flight.lock().unwrap().insert(*peer, token);
+        token
+    }
+
+    /// Validates a pong's echoed proof against the outstanding challenge for `peer`, or
+    /// short-circuits to `true` if `peer` was already verified within [`PING_VERIFICATION_TTL`].
+    fn verify(&self, peer: &PeerId, proof: Option<[u8; 32]>) -> bool {
+        let now = Instant::now();
+
+        if let Some(entry) = self.verified.lock().unwrap().get(peer) {
+            if now.duration_since(entry.verified_at) < PING_VERIFICATION_TTL {
+                return true;
+            }
+        }
+
+        let Some(echoed) = proof else {
+            return false;
+        };
+
+        let Some(token) = self.in_flight.lock().unwrap().remove(peer) else {
+            return false;
+        };
+
+        let expected: [u8; 32] = Keccak256::new()
+            .chain_update(token)
+            .chain_update(peer.to_bytes())
+            .finalize()
+            .into();
+
+        if expected == echoed {
+            self.verified.lock().unwrap().insert(*peer, VerifiedEntry { verified_at: now, token });
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Smoothing factor for the decaying quality score kept by [`HealthTracker`]: weight given to
+/// the newest ping outcome against everything observed so far.
+const HEALTH_QUALITY_ALPHA: f64 = 0.2;
+
+/// A peer whose decaying quality score falls below this threshold is reported as being in
+/// "client mode" by [`PingExternalInteractions::is_client_mode`].
+const CLIENT_MODE_QUALITY_THRESHOLD: f64 = 0.3;
+
+/// Metadata key carrying the peer's current decaying quality score as observed by the
+/// heartbeat, alongside [`PEER_METADATA_PROTOCOL_VERSION`].
+const PEER_METADATA_QUALITY_SCORE: &str = "quality_score";
+
+#[derive(Debug, Clone, Copy)]
+struct PeerHealth {
+    rtt_avg: Duration,
+    successes: u32,
+    attempts: u32,
+    quality: f64,
+}
+
+impl Default for PeerHealth {
+    fn default() -> Self {
+        Self {
+            rtt_avg: Duration::ZERO,
+            successes: 0,
+            attempts: 0,
+            quality: 0.5,
+        }
+    }
+}
+
+impl PeerHealth {
+    fn success_ratio(&self) -> f64 {
+        if self.attempts == 0 {
+            0.0
+        } else {
+            self.successes as f64 / self.attempts as f64
+        }
+    }
+}
+
+/// Per-peer RTT, success ratio, and decaying quality score fed by every finished ping.
+///
+/// This is the heart of the health-scoring mechanism the heartbeat path has long needed: a
+/// single successful pong is no longer treated the same as a peer that only occasionally
+/// answers, which lets [`PingExternalInteractions::is_client_mode`] flag flaky peers instead
+/// of reporting them as fully healthy.
+#[derive(Debug, Default)]
+struct HealthTracker {
+    peers: Mutex<HashMap<PeerId, PeerHealth>>,
+}
+
+impl HealthTracker {
+    fn record(&self, peer: &PeerId, rtt: Option<Duration>) -> PeerHealth {
+        let mut peers = self.peers.lock().unwrap();
+        let entry = peers.entry(*peer).or_default();
+
+        entry.attempts += 1;
+        let outcome = if let Some(rtt) = rtt {
+            entry.successes += 1;
+            entry.rtt_avg = if entry.successes == 1 {
+                rtt
+            } else {
+                entry.rtt_avg.mul_f64(1.0 - HEALTH_QUALITY_ALPHA) + rtt.mul_f64(HEALTH_QUALITY_ALPHA)
+            };
+            1.0
+        } else {
+            0.0
+        };
+
+        entry.quality = HEALTH_QUALITY_ALPHA * outcome + (1.0 - HEALTH_QUALITY_ALPHA) * entry.quality;
+        *entry
+    }
+
+    fn is_client_mode(&self, peer: &PeerId) -> Option<bool> {
+        self.peers.lock().unwrap().get(peer).map(|p| p.quality < CLIENT_MODE_QUALITY_THRESHOLD)
+    }
+}
+
 /// Implementor of the ping external API.
 ///
 /// Ping requires functionality from external components in order to obtain
@@ -21,22 +174,64 @@ use crate::{adaptors::network::ExternalNetworkInteractions, constants::PEER_META
 #[derive(Clone)]
 pub struct PingExternalInteractions {
     network: Arc<RwLock<Network<ExternalNetworkInteractions>>>,
+    cache: Arc<PingCache>,
+    health: Arc<HealthTracker>,
 }
 
 impl PingExternalInteractions {
     pub fn new(network: Arc<RwLock<Network<ExternalNetworkInteractions>>>) -> Self {
-        Self { network }
+        Self {
+            network,
+            cache: Arc::new(PingCache::default()),
+            health: Arc::new(HealthTracker::default()),
+        }
+    }
+
+    /// Issues a new endpoint-proof challenge token for `peer`, to be embedded in the next probe
+    /// dispatched to it. See [`PingCache::issue_challenge`].
+    pub fn issue_challenge(&self, peer: &PeerId) -> [u8; 32] {
+        self.cache.issue_challenge(peer)
+    }
+
+    /// The fraction of pings to `peer` that have succeeded, or `0.0` if nothing has been
+    /// recorded for it yet.
+    pub fn success_ratio(&self, peer: &PeerId) -> f64 {
+        self.health
+            .peers
+            .lock()
+            .unwrap()
+            .get(peer)
+            .map(PeerHealth::success_ratio)
+            .unwrap_or_default()
+    }
+
+    /// Whether `peer`'s decaying quality score has fallen below [`CLIENT_MODE_QUALITY_THRESHOLD`]
+    /// (client mode). This only reports the classification; this adaptor does not exclude such a
+    /// peer from being advertised or picked as a relay hop. Returns `None` if no ping has ever
+    /// been recorded.
+    pub fn is_client_mode(&self, peer: &PeerId) -> Option<bool> {
+        self.health.is_client_mode(peer)
     }
 }
 
 #[async_trait]
 impl PingExternalAPI for PingExternalInteractions {
-    async fn on_finished_ping(&self, peer: &PeerId, result: Result, version: String) {
+    async fn on_finished_ping(&self, peer: &PeerId, result: Result, version: String, proof: Option<[u8; 32]>) {
         // This logic deserves a larger refactor of the entire heartbeat mechanism, but
         // for now it is suffcient to fill out metadata only on successful pongs.
+        let verified = self.cache.verify(peer, proof);
+        let health = self.health.record(peer, result.as_ref().ok().copied());
+
+        // Store a fresh in-flight challenge for this peer, so the next pong's proof is checked
+        // against a new token. Nothing here hands this token to the outgoing probe: calling
+        // `issue_challenge` replaces it with another new token rather than returning it.
+        self.cache.issue_challenge(peer);
+
         let metadata = if result.is_ok() {
             let mut map = std::collections::HashMap::new();
             map.insert(PEER_METADATA_PROTOCOL_VERSION.to_owned(), version);
+            map.insert(PEER_METADATA_VERIFIED.to_owned(), verified.to_string());
+            map.insert(PEER_METADATA_QUALITY_SCORE.to_owned(), health.quality.to_string());
             Some(map)
         } else {
             None
@@ -46,6 +241,139 @@ impl PingExternalAPI for PingExternalInteractions {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libp2p_identity::PeerId;
+
+    fn expected_proof(token: [u8; 32], peer: &PeerId) -> [u8; 32] {
+        Keccak256::new().chain_update(token).chain_update(peer.to_bytes()).finalize().into()
+    }
+
+    #[test]
+    fn verify_should_accept_a_correct_proof_and_remember_the_peer_as_verified() {
+        let cache = PingCache::default();
+        let peer = PeerId::random();
+
+        let token = cache.issue_challenge(&peer);
+        assert!(cache.verify(&peer, Some(expected_proof(token, &peer))));
+
+        // Remembered: a later call with no proof at all still short-circuits to verified.
+        assert!(cache.verify(&peer, None));
+    }
+
+    #[test]
+    fn verify_should_reject_a_wrong_proof_and_consume_the_token() {
+        let cache = PingCache::default();
+        let peer = PeerId::random();
+
+        let token = cache.issue_challenge(&peer);
+        let wrong_proof = {
+            let mut bogus = expected_proof(token, &peer);
+            bogus[0] ^= 0xff;
+            bogus
+        };
+        assert!(!cache.verify(&peer, Some(wrong_proof)));
+
+        // The in-flight token was consumed by the failed attempt, so even the correct proof
+        // is now rejected: there is nothing left to check it against.
+        assert!(!cache.verify(&peer, Some(expected_proof(token, &peer))));
+    }
+
+    #[test]
+    fn verify_should_reject_an_unsolicited_proof_with_no_in_flight_challenge() {
+        let cache = PingCache::default();
+        let peer = PeerId::random();
+
+        let bogus_token = [0u8; 32];
+        assert!(!cache.verify(&peer, Some(expected_proof(bogus_token, &peer))));
+    }
+
+    #[test]
+    fn verify_should_force_re_verification_once_the_ttl_has_expired() {
+        let cache = PingCache::default();
+        let peer = PeerId::random();
+
+        cache.verified.lock().unwrap().insert(
+            peer,
+            VerifiedEntry {
+                verified_at: Instant::now() - (PING_VERIFICATION_TTL + Duration::from_secs(1)),
+                token: [0u8; 32],
+            },
+        );
+
+        // The TTL has lapsed, so the short-circuit no longer applies, and with no in-flight
+        // challenge to check a fresh proof against, the peer is treated as unverified again.
+        assert!(!cache.verify(&peer, None));
+    }
+
+    #[test]
+    fn health_tracker_should_average_rtt_over_successive_successes() {
+        let tracker = HealthTracker::default();
+        let peer = PeerId::random();
+
+        let first = tracker.record(&peer, Some(Duration::from_millis(100)));
+        assert_eq!(first.rtt_avg, Duration::from_millis(100));
+
+        let second = tracker.record(&peer, Some(Duration::from_millis(200)));
+        // rtt_avg = 100ms * (1 - 0.2) + 200ms * 0.2 = 120ms.
+        assert_eq!(second.rtt_avg, Duration::from_millis(120));
+    }
+
+    #[test]
+    fn health_tracker_should_decay_the_quality_score_towards_recent_outcomes() {
+        let tracker = HealthTracker::default();
+        let peer = PeerId::random();
+
+        // Starting from the 0.5 default, a failure pulls the quality down by (1 - alpha).
+        let health = tracker.record(&peer, None);
+        assert!((health.quality - 0.4).abs() < 1e-9);
+
+        let health = tracker.record(&peer, Some(Duration::from_millis(50)));
+        // 0.2 * 1.0 + 0.8 * 0.4 = 0.52.
+        assert!((health.quality - 0.52).abs() < 1e-9);
+    }
+
+    #[test]
+    fn health_tracker_success_ratio_should_reflect_recorded_attempts() {
+        let tracker = HealthTracker::default();
+        let peer = PeerId::random();
+
+        tracker.record(&peer, Some(Duration::from_millis(10)));
+        tracker.record(&peer, None);
+        tracker.record(&peer, Some(Duration::from_millis(10)));
+
+        assert_eq!(
+            tracker.peers.lock().unwrap().get(&peer).map(PeerHealth::success_ratio),
+            Some(2.0 / 3.0)
+        );
+    }
+
+    #[test]
+    fn is_client_mode_should_be_none_until_a_ping_has_been_recorded() {
+        let tracker = HealthTracker::default();
+        let peer = PeerId::random();
+
+        assert_eq!(tracker.is_client_mode(&peer), None);
+    }
+
+    #[test]
+    fn is_client_mode_should_flip_once_the_quality_score_crosses_the_threshold() {
+        let tracker = HealthTracker::default();
+        let peer = PeerId::random();
+
+        // Quality decays as 0.8^n from the 0.5 default under repeated failures: 0.4, 0.32, 0.256.
+        tracker.record(&peer, None);
+        assert_eq!(tracker.is_client_mode(&peer), Some(false));
+
+        tracker.record(&peer, None);
+        assert_eq!(tracker.is_client_mode(&peer), Some(false));
+
+        tracker.record(&peer, None);
+        assert_eq!(tracker.is_client_mode(&peer), Some(true));
+    }
+}
+
 #[cfg(feature = "wasm")]
 pub mod wasm {
     use super::*;
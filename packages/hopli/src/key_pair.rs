@@ -1,19 +1,83 @@
 use crate::utils::HelperErrors;
-use hoprd_keypair::key_pair::HoprKeys;
-use log::warn;
-use std::{fs, path::PathBuf};
+use core_crypto::types::Signature;
+use hoprd_keypair::key_pair::{HoprKeys, KeystoreParams};
+use log::{info, warn};
+use rayon::iter::{ParallelBridge, ParallelIterator};
+use std::{
+    fs,
+    path::PathBuf,
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    sync::Mutex,
+    time::Instant,
+};
+
+/// Number of leading hex nibbles above which a requested vanity prefix is considered
+/// infeasible to search for (expected attempts grow as `16^len`).
+const VANITY_PREFIX_WARN_THRESHOLD: usize = 7;
+
+/// Recursively collects all identity files under `identity_directory`, at any depth, whose
+/// file name contains `"id"` and (if given) starts with `identity_prefix`.
+pub fn get_files(identity_directory: &str, identity_prefix: &Option<String>) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut pending = vec![PathBuf::from(identity_directory)];
+
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+
+        for entry in entries.into_iter().filter_map(|e| e.ok()) {
+            let path = entry.path();
+
+            if path.is_dir() {
+                pending.push(path);
+                continue;
+            }
+
+            if !path.is_file() {
+                continue;
+            }
+
+            let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            if !name.contains("id") {
+                continue;
+            }
+
+            let matches_prefix = match identity_prefix {
+                Some(identity_prefix) => path
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .is_some_and(|stem| stem.starts_with(identity_prefix.as_str())),
+                None => true,
+            };
+
+            if matches_prefix {
+                files.push(path);
+            }
+        }
+    }
+
+    files.sort();
+    files
+}
 
 /// Decrypt identity files and returns an vec of PeerIds and Ethereum Addresses
 ///
+/// Decryption is CPU-bound (scrypt), so files are decrypted in parallel across a thread pool;
+/// the returned `Vec<HoprKeys>` is sorted by file path beforehand so that ordering stays
+/// deterministic regardless of which worker finishes first.
+///
 /// # Arguments
 ///
 /// * `identity_directory` - Directory to all the identity files
 /// * `password` - Password to unlock all the identity files
 /// * `identity_prefix` - Prefix of identity files. Only identity files with the provided are decrypted with the password
 pub fn read_identities(files: Vec<PathBuf>, password: &String) -> Result<Vec<HoprKeys>, HelperErrors> {
-    let mut results: Vec<HoprKeys> = Vec::with_capacity(files.len());
+    let results: Mutex<Vec<(PathBuf, HoprKeys)>> = Mutex::new(Vec::with_capacity(files.len()));
 
-    for file in files.iter() {
+    files.into_iter().par_bridge().try_for_each(|file| {
         let file_str = file
             .to_str()
             .ok_or(HelperErrors::IncorrectFilename(file.to_string_lossy().to_string()))?;
@@ -23,17 +87,22 @@ pub fn read_identities(files: Vec<PathBuf>, password: &String) -> Result<Vec<Hop
         match HoprKeys::read_eth_keystore(file_str, password) {
             Ok((keys, needs_migration)) => {
                 if needs_migration {
-                    keys.write_eth_keystore(file_str, password, false)?
+                    keys.write_eth_keystore(file_str, password, &KeystoreParams::interactive())?
                 }
-                results.push(keys)
+                results.lock().unwrap().push((file.clone(), keys));
             }
             Err(e) => {
                 warn!("Could not decrypt keystore file at {}. {}", file_str, e.to_string())
             }
         }
-    }
 
-    Ok(results)
+        Ok::<(), HelperErrors>(())
+    })?;
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    Ok(results.into_iter().map(|(_, keys)| keys).collect())
 }
 
 /// Create one identity file and return the ethereum address
@@ -43,7 +112,14 @@ pub fn read_identities(files: Vec<PathBuf>, password: &String) -> Result<Vec<Hop
 /// * `dir_name` - Directory to the storage of an identity file
 /// * `password` - Password to encrypt the identity file
 /// * `name` - Prefix of identity files.
-pub fn create_identity(dir_name: &str, password: &str, maybe_name: &Option<String>) -> Result<HoprKeys, HelperErrors> {
+/// * `keystore_params` - Scrypt cost to encrypt the identity file with, e.g. [`KeystoreParams::interactive`]
+///   or [`KeystoreParams::sensitive`]
+pub fn create_identity(
+    dir_name: &str,
+    password: &str,
+    maybe_name: &Option<String>,
+    keystore_params: &KeystoreParams,
+) -> Result<HoprKeys, HelperErrors> {
     // create dir if not exist
     fs::create_dir_all(dir_name)?;
 
@@ -62,23 +138,237 @@ pub fn create_identity(dir_name: &str, password: &str, maybe_name: &Option<Strin
         None => format!("{dir_name}/{}.id", { keys.id.to_string() }),
     };
 
-    keys.write_eth_keystore(&file_path, password, false)?;
+    keys.write_eth_keystore(&file_path, password, keystore_params)?;
 
     Ok(keys)
 }
 
+/// Create one identity file whose Ethereum chain-key address starts with `prefix`
+/// (a case-insensitive hex string, without the `0x` prefix), and return it.
+///
+/// # Arguments
+///
+/// * `dir_name` - Directory to the storage of an identity file
+/// * `password` - Password to encrypt the identity file
+/// * `prefix` - Required hex prefix of the resulting chain-key address
+/// * `name` - Prefix of identity files.
+/// * `max_attempts` - Optional bound on the number of keys generated before giving up
+pub fn create_identity_with_prefix(
+    dir_name: &str,
+    password: &str,
+    prefix: &str,
+    maybe_name: &Option<String>,
+    max_attempts: Option<u64>,
+) -> Result<HoprKeys, HelperErrors> {
+    let prefix = prefix.strip_prefix("0x").unwrap_or(prefix).to_lowercase();
+
+    if prefix.is_empty() || !prefix.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(HelperErrors::InvalidInput(format!(
+            "vanity prefix '{prefix}' is not valid lowercase hex"
+        )));
+    }
+
+    if prefix.len() > VANITY_PREFIX_WARN_THRESHOLD {
+        warn!(
+            "vanity prefix '{prefix}' is {} nibbles long, expect roughly 16^{} attempts to find a match",
+            prefix.len(),
+            prefix.len()
+        );
+    }
+
+    fs::create_dir_all(dir_name)?;
+
+    let found = AtomicBool::new(false);
+    let attempts = AtomicU64::new(0);
+    let start = Instant::now();
+
+    let keys = (0..max_attempts.unwrap_or(u64::MAX))
+        .par_bridge()
+        .find_map_any(|_| {
+            if found.load(Ordering::Relaxed) {
+                return None;
+            }
+
+            if let Some(limit) = max_attempts {
+                if attempts.fetch_add(1, Ordering::Relaxed) >= limit {
+                    return None;
+                }
+            } else {
+                attempts.fetch_add(1, Ordering::Relaxed);
+            }
+
+            let candidate = HoprKeys::new();
+            let address = candidate.chain_key.1.to_address().to_string();
+            let address = address.strip_prefix("0x").unwrap_or(&address).to_lowercase();
+
+            if address.starts_with(&prefix) {
+                found.store(true, Ordering::Relaxed);
+                Some(candidate)
+            } else {
+                None
+            }
+        })
+        .ok_or_else(|| {
+            HelperErrors::UnableToCreateIdentity(format!(
+                "failed to find an address matching prefix '{prefix}' within {} attempts",
+                attempts.load(Ordering::Relaxed)
+            ))
+        })?;
+
+    info!(
+        "found vanity address after {} attempts in {:?}",
+        attempts.load(Ordering::Relaxed),
+        start.elapsed()
+    );
+
+    let file_path = match maybe_name {
+        Some(name) => {
+            if name.ends_with(".id") {
+                format!("{dir_name}/{name}")
+            } else {
+                format!("{dir_name}/{name}.id")
+            }
+        }
+        None => format!("{dir_name}/{}.id", { keys.id.to_string() }),
+    };
+
+    keys.write_eth_keystore(&file_path, password, &KeystoreParams::interactive())?;
+
+    Ok(keys)
+}
+
+/// Create one identity file deterministically derived from a BIP-39 mnemonic phrase, and
+/// return it.
+///
+/// # Arguments
+///
+/// * `dir_name` - Directory to the storage of an identity file
+/// * `password` - Password to encrypt the identity file
+/// * `phrase` - BIP-39 mnemonic phrase to derive the identity from
+/// * `name` - Prefix of identity files.
+pub fn create_identity_from_mnemonic(
+    dir_name: &str,
+    password: &str,
+    phrase: &str,
+    maybe_name: &Option<String>,
+) -> Result<HoprKeys, HelperErrors> {
+    fs::create_dir_all(dir_name)?;
+
+    let keys = HoprKeys::from_mnemonic(phrase)
+        .map_err(|e| HelperErrors::InvalidInput(format!("could not derive identity from mnemonic: {e}")))?;
+
+    let file_path = match maybe_name {
+        Some(name) => {
+            if name.ends_with(".id") {
+                format!("{dir_name}/{name}")
+            } else {
+                format!("{dir_name}/{name}.id")
+            }
+        }
+        None => format!("{dir_name}/{}.id", { keys.id.to_string() }),
+    };
+
+    keys.write_eth_keystore(&file_path, password, &KeystoreParams::interactive())?;
+
+    Ok(keys)
+}
+
+/// Signs `message` with `keys`' chain (Ethereum) private key, using the same keccak256-then-ECDSA
+/// scheme as ethkey's `sign`. The resulting signature, together with `message`, is enough for
+/// anyone to recover the signing address via [`recover_signer`] without ever seeing the key.
+///
+/// Delegates to [`HoprKeys::sign_chain`] rather than re-implementing the same signing scheme here.
+pub fn sign_message(keys: &HoprKeys, message: &[u8]) -> Signature {
+    keys.sign_chain(message)
+}
+
+/// Checks that `signature` over `message` was produced by the chain key belonging to `address`
+/// (a hex Ethereum address, compared case-insensitively and with or without a `0x` prefix).
+///
+/// Delegates to [`hoprd_keypair::key_pair::verify_address`] rather than re-implementing the same
+/// recover-then-compare logic here.
+pub fn verify_address(address: &str, signature: &Signature, message: &[u8]) -> Result<bool, HelperErrors> {
+    hoprd_keypair::key_pair::verify_address(address, message, signature)
+        .map_err(|e| HelperErrors::InvalidInput(format!("could not recover signer: {e}")))
+}
+
+/// Recovers the Ethereum address that produced `signature` over `message`, without needing the
+/// signer's key or identity file — mirrors ethkey's `recover`.
+///
+/// Delegates to [`HoprKeys::recover`] rather than re-implementing the same signature-recovery
+/// logic here.
+pub fn recover_signer(signature: &Signature, message: &[u8]) -> Result<String, HelperErrors> {
+    let public_key = HoprKeys::recover(message, signature)
+        .map_err(|e| HelperErrors::InvalidInput(format!("could not recover signer: {e}")))?;
+
+    Ok(public_key.to_address().to_string())
+}
+
 #[cfg(test)]
 mod tests {
-    use std::path::Path;
     use utils_types::traits::PeerIdLike;
 
     use super::*;
 
+    #[test]
+    fn sign_message_should_be_verifiable_and_recoverable() {
+        let keys = HoprKeys::new();
+        let message = b"prove control of this node's on-chain address";
+
+        let signature = sign_message(&keys, message);
+        let address = keys.chain_key.1.to_address().to_string();
+
+        assert!(verify_address(&address, &signature, message).unwrap());
+        assert!(!verify_address(&address, &signature, b"a different message").unwrap());
+
+        let recovered = recover_signer(&signature, message).unwrap();
+        assert_eq!(recovered.to_lowercase(), address.to_lowercase());
+    }
+
+    #[test]
+    fn create_identity_with_prefix_should_reject_non_hex_prefix() {
+        let path = "./tmp_vanity_invalid";
+        assert!(create_identity_with_prefix(path, "password", "not-hex", &None, Some(10)).is_err());
+    }
+
+    #[test]
+    fn create_identity_with_prefix_should_find_a_matching_address() {
+        let path = "./tmp_vanity";
+        let pwd = "password_vanity";
+
+        // A single hex nibble prefix matches within a handful of attempts on average.
+        let keys =
+            create_identity_with_prefix(path, pwd, "0", &Some(String::from("vanity")), Some(1_000_000)).unwrap();
+        assert!(keys
+            .chain_key
+            .1
+            .to_address()
+            .to_string()
+            .trim_start_matches("0x")
+            .starts_with('0'));
+
+        remove_json_keystore(path).map_err(|err| println!("{:?}", err)).ok();
+    }
+
+    #[test]
+    fn create_identity_from_mnemonic_should_be_deterministic() {
+        let path = "./tmp_from_mnemonic";
+        let pwd = "password_from_mnemonic";
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+        let keys = create_identity_from_mnemonic(path, pwd, phrase, &Some(String::from("from-mnemonic"))).unwrap();
+        let expected = HoprKeys::from_mnemonic(phrase).unwrap();
+
+        assert_eq!(keys.chain_key.1, expected.chain_key.1);
+
+        remove_json_keystore(path).map_err(|err| println!("{:?}", err)).ok();
+    }
+
     #[test]
     fn create_identities_from_directory_with_id_files() {
         let path = "./tmp_create";
         let pwd = "password_create";
-        match create_identity(path, pwd, &Some(String::from("node1"))) {
+        match create_identity(path, pwd, &Some(String::from("node1")), &KeystoreParams::weak_for_testing()) {
             Ok(_) => assert!(true),
             _ => assert!(false),
         }
@@ -89,7 +379,7 @@ mod tests {
     fn read_identities_from_directory_with_id_files() {
         let path = "./tmp_1";
         let pwd = "password";
-        let created_id = create_identity(path, pwd, &None).unwrap();
+        let created_id = create_identity(path, pwd, &None, &KeystoreParams::weak_for_testing()).unwrap();
 
         // created and the read id is identical
         let files = get_files(path, &None);
@@ -110,7 +400,7 @@ mod tests {
         let path = "./tmp_2";
         let pwd = "password";
         let wrong_pwd = "wrong_password";
-        create_identity(path, pwd, &None).unwrap();
+        create_identity(path, pwd, &None, &KeystoreParams::weak_for_testing()).unwrap();
         let files = get_files(path, &None);
         match read_identities(files, &wrong_pwd.to_string()) {
             Ok(val) => assert_eq!(val.len(), 0),
@@ -133,7 +423,7 @@ mod tests {
     fn read_identities_from_tmp_folder() {
         let path = "./tmp_4";
         let pwd = "local";
-        create_identity(path, pwd, &Some(String::from("local-alice"))).unwrap();
+        create_identity(path, pwd, &Some(String::from("local-alice")), &KeystoreParams::weak_for_testing()).unwrap();
         let files = get_files(path, &None);
         match read_identities(files, &pwd.to_string()) {
             Ok(val) => assert_eq!(val.len(), 1),
@@ -146,7 +436,7 @@ mod tests {
     fn read_identities_from_tmp_folder_with_prefix() {
         let path = "./tmp_5";
         let pwd = "local";
-        create_identity(path, pwd, &Some(String::from("local-alice"))).unwrap();
+        create_identity(path, pwd, &Some(String::from("local-alice")), &KeystoreParams::weak_for_testing()).unwrap();
         let files = get_files(path, &Some("local".to_string()));
         match read_identities(files, &pwd.to_string()) {
             Ok(val) => assert_eq!(val.len(), 1),
@@ -159,7 +449,7 @@ mod tests {
     fn read_identities_from_tmp_folder_no_match() {
         let path = "./tmp_6";
         let pwd = "local";
-        create_identity(path, pwd, &Some(String::from("local-alice"))).unwrap();
+        create_identity(path, pwd, &Some(String::from("local-alice")), &KeystoreParams::weak_for_testing()).unwrap();
         let files = get_files(path, &Some("npm-".to_string()));
         match read_identities(files, &pwd.to_string()) {
             Ok(val) => assert_eq!(val.len(), 0),
@@ -172,7 +462,7 @@ mod tests {
     fn read_identities_from_tmp_folder_with_wrong_prefix() {
         let path = "./tmp_7";
         let pwd = "local";
-        create_identity(path, pwd, &Some(String::from("local-alice"))).unwrap();
+        create_identity(path, pwd, &Some(String::from("local-alice")), &KeystoreParams::weak_for_testing()).unwrap();
 
         let files = get_files(path, &Some("alice".to_string()));
         match read_identities(files, &pwd.to_string()) {
@@ -203,6 +493,11 @@ mod tests {
         assert_eq!(val[0].chain_key.1.to_peerid_str(), alice_peer_id);
         assert_eq!(val[0].chain_key.1.to_address().to_string(), alice_address);
 
+        // the weak `n=2` keystore on disk should have been transparently re-encrypted with the
+        // "interactive" profile's stronger parameters.
+        let rewritten = fs::read_to_string(PathBuf::from(path).join(&name)).unwrap();
+        assert!(!rewritten.contains("\"n\":2"));
+
         remove_json_keystore(path).map_err(|err| println!("{:?}", err)).ok();
     }
 
@@ -214,29 +509,4 @@ mod tests {
         }
     }
 
-    fn get_files(identity_directory: &str, identity_prefix: &Option<String>) -> Vec<PathBuf> {
-        // early return if failed in reading identity directory
-        let directory = fs::read_dir(Path::new(identity_directory)).unwrap();
-
-        // read all the files from the directory that contains
-        // 1) "id" in its name
-        // 2) the provided idetity_prefix
-        let files: Vec<PathBuf> = directory
-            .into_iter() // read all the files from the directory
-            .filter(|r| r.is_ok()) // Get rid of Err variants for Result<DirEntry>
-            .map(|r| r.unwrap().path()) // Read all the files from the given directory
-            .filter(|r| r.is_file()) // Filter out folders
-            .filter(|r| r.to_str().unwrap().contains("id")) // file name should contain "id"
-            .filter(|r| match &identity_prefix {
-                Some(identity_prefix) => r
-                    .file_stem()
-                    .unwrap()
-                    .to_str()
-                    .unwrap()
-                    .starts_with(identity_prefix.as_str()),
-                _ => true,
-            })
-            .collect();
-        files
-    }
 }
@@ -6,10 +6,14 @@ use aes::{
     cipher::{self, InnerIvInit, KeyInit, StreamCipherCore},
     Aes128,
 };
-use core_crypto::types::{OffchainPublicKey, PublicKey};
+use bip39::{Language, Mnemonic};
+use core_crypto::types::{OffchainPublicKey, OffchainSignature, PublicKey, Signature};
 use getrandom::getrandom;
 use hex;
+use hmac::{Hmac, Mac};
+use pbkdf2::pbkdf2_hmac;
 use scrypt::{scrypt, Params as ScryptParams};
+use sha2::{Sha256, Sha512};
 use serde::{ser::SerializeStruct, Serialize, Serializer};
 use serde_json::{from_str as from_json_string, to_string as to_json_string};
 use sha3::{digest::Update, Digest, Keccak256};
@@ -38,6 +42,56 @@ const CHAIN_KEY_LENGTH: usize = 32;
 pub type PacketKey = [u8; PACKET_KEY_LENGTH];
 pub type ChainKey = [u8; CHAIN_KEY_LENGTH];
 
+/// Scrypt KDF cost parameters controlling how expensive a keystore password is to brute-force.
+/// HOPR writes keystores only with scrypt (see [`write_eth_keystore`]), so unlike a
+/// general-purpose secret manager there is no Argon2id alternative to pick here.
+///
+/// Use one of the named profiles rather than constructing this directly, the same way acmed's
+/// key-strength posture distinguishes cost appropriate to interactive unlocks from cost
+/// appropriate to long-lived, high-value key material.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeystoreParams {
+    pub log_n: u8,
+    pub r: u32,
+    pub p: u32,
+}
+
+impl KeystoreParams {
+    /// Cost tuned for keystores unlocked interactively on commodity hardware. This is the
+    /// strength [`read_eth_keystore`]'s auto re-encryption policy treats as the minimum.
+    pub const fn interactive() -> Self {
+        Self {
+            log_n: HOPR_KDF_PARAMS_LOG_N,
+            r: HOPR_KDF_PARAMS_R,
+            p: HOPR_KDF_PARAMS_P,
+        }
+    }
+
+    /// Higher cost for keystores protecting funds or long-lived node identities, where the
+    /// extra KDF latency at unlock time is an acceptable trade for more brute-force resistance.
+    pub const fn sensitive() -> Self {
+        Self {
+            log_n: HOPR_KDF_PARAMS_LOG_N + 2,
+            r: HOPR_KDF_PARAMS_R,
+            p: HOPR_KDF_PARAMS_P,
+        }
+    }
+
+    /// Minimal cost used only in tests, where keystores are written and read back thousands of
+    /// times and real KDF cost would make the suite unbearably slow.
+    pub const fn weak_for_testing() -> Self {
+        Self {
+            log_n: 1,
+            r: HOPR_KDF_PARAMS_R,
+            p: HOPR_KDF_PARAMS_P,
+        }
+    }
+
+    fn n(&self) -> u32 {
+        2u32.pow(self.log_n as u32)
+    }
+}
+
 // Current version, deviates from pre 2.0
 const VERSION: u32 = 2;
 
@@ -208,6 +262,36 @@ impl PartialEq for HoprKeys {
     }
 }
 
+/// Derives a 32-byte scalar candidate from `seed` under the given derivation `label`,
+/// re-hashing with an incrementing counter until `validate` accepts the candidate (i.e. it is
+/// neither zero nor out of range for the target curve).
+fn derive_valid_scalar(seed: &[u8; 64], label: &str, validate: impl Fn(&[u8; 32]) -> bool) -> [u8; 32] {
+    for attempt in 0u32.. {
+        let mut mac = Hmac::<Sha512>::new_from_slice(seed).expect("HMAC accepts any key length");
+        mac.update(label.as_bytes());
+        mac.update(&attempt.to_be_bytes());
+        let digest = mac.finalize().into_bytes();
+
+        let mut candidate = [0u8; 32];
+        candidate.copy_from_slice(&digest[..32]);
+
+        if validate(&candidate) {
+            return candidate;
+        }
+    }
+
+    unreachable!("a valid scalar must be found within u32::MAX attempts")
+}
+
+/// Checks that `signature` over `msg` was produced by the chain key belonging to `address` (a hex
+/// Ethereum address, compared case-insensitively and with or without a `0x` prefix).
+pub fn verify_address(address: &str, msg: &[u8], signature: &Signature) -> Result<bool> {
+    let recovered = HoprKeys::recover(msg, signature)?;
+    let expected = address.trim_start_matches("0x");
+
+    Ok(recovered.to_address().to_string().trim_start_matches("0x").eq_ignore_ascii_case(expected))
+}
+
 impl HoprKeys {
     pub fn new() -> Self {
         Self {
@@ -217,8 +301,187 @@ impl HoprKeys {
         }
     }
 
+    /// Deterministically derives the node identity (both packet key and chain key) from a
+    /// BIP-39 mnemonic phrase, so an operator can regenerate the same identity on a new
+    /// machine from a backup phrase instead of copying the encrypted keystore file.
+    ///
+    /// The phrase is normalized and turned into a 64-byte seed via BIP-39's standard
+    /// PBKDF2-HMAC-SHA512 (2048 rounds), and each key is derived from that seed under a
+    /// fixed, HOPR-specific label, re-deriving on the rare candidate that is zero or falls
+    /// outside the valid scalar range for its curve.
+    pub fn from_mnemonic(phrase: &str) -> Result<Self> {
+        let mnemonic = Mnemonic::parse_in_normalized(Language::English, phrase)
+            .map_err(|e| KeyPairError::GeneralError(format!("invalid mnemonic: {e}")))?;
+        let seed = mnemonic.to_seed("");
+
+        let packet_key = derive_valid_scalar(&seed, "hopr-identity-v1/packet-key", |candidate| {
+            OffchainPublicKey::from_privkey(&candidate[..]).is_ok()
+        });
+        let chain_key = derive_valid_scalar(&seed, "hopr-identity-v1/chain-key", |candidate| {
+            PublicKey::from_privkey(&candidate[..]).is_ok()
+        });
+
+        (packet_key, chain_key).try_into()
+    }
+
+    /// Recovers a mnemonic phrase with a small number of uncertain words, modeled on ethkey's
+    /// `brain_recover`. `phrase_template` is a space-separated phrase where each uncertain
+    /// word is replaced by `"?"`; every combination of BIP-39 wordlist entries for those
+    /// positions is tried until the derived chain-key address matches `expected_chain_address`
+    /// (compared case-insensitively, with or without a `0x` prefix).
+    pub fn recover_identity(phrase_template: &str, expected_chain_address: &str) -> Result<String> {
+        let expected = expected_chain_address.trim_start_matches("0x");
+        let words: Vec<&str> = phrase_template.split_whitespace().collect();
+        let wordlist = Language::English.word_list();
+
+        let unknown_positions: Vec<usize> = words
+            .iter()
+            .enumerate()
+            .filter(|(_, w)| **w == "?")
+            .map(|(i, _)| i)
+            .collect();
+
+        let matches = |phrase: &str| -> bool {
+            HoprKeys::from_mnemonic(phrase)
+                .map(|keys| {
+                    keys.chain_key
+                        .1
+                        .to_address()
+                        .to_string()
+                        .trim_start_matches("0x")
+                        .eq_ignore_ascii_case(expected)
+                })
+                .unwrap_or(false)
+        };
+
+        if unknown_positions.is_empty() {
+            return if matches(phrase_template) {
+                Ok(phrase_template.to_owned())
+            } else {
+                Err(KeyPairError::GeneralError(
+                    "derived address does not match the expected address".into(),
+                ))
+            };
+        }
+
+        let mut indices = vec![0usize; unknown_positions.len()];
+        loop {
+            let mut candidate_words = words.clone();
+            for (slot, &pos) in unknown_positions.iter().enumerate() {
+                candidate_words[pos] = wordlist[indices[slot]];
+            }
+            let candidate_phrase = candidate_words.join(" ");
+
+            if matches(&candidate_phrase) {
+                return Ok(candidate_phrase);
+            }
+
+            // Advance the odometer over candidate word indices; once every slot has wrapped
+            // around we have exhausted all combinations.
+            let mut slot = 0;
+            loop {
+                indices[slot] += 1;
+                if indices[slot] < wordlist.len() {
+                    break;
+                }
+                indices[slot] = 0;
+                slot += 1;
+                if slot == indices.len() {
+                    return Err(KeyPairError::GeneralError(
+                        "no candidate phrase matched the expected address".into(),
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Signs `msg` with this identity's chain (Ethereum, secp256k1) key, using the same
+    /// keccak256-then-ECDSA scheme as ethkey's `sign`. The resulting signature, together with
+    /// `msg`, is enough for anyone to recover the signing address via [`Self::recover`] without
+    /// ever seeing the key.
+    pub fn sign_chain(&self, msg: &[u8]) -> Signature {
+        Signature::sign_message(msg, &self.chain_key.0)
+    }
+
+    /// Recovers the chain public key that produced `signature` over `msg`, without needing the
+    /// signer's key or identity file — mirrors ethkey's `recover`.
+    pub fn recover(msg: &[u8], signature: &Signature) -> Result<PublicKey> {
+        PublicKey::from_signature(msg, signature)
+            .map_err(|e| KeyPairError::GeneralError(format!("could not recover signer: {e}")))
+    }
+
+    /// Checks that `signature` over `msg` was produced by this identity's chain key.
+    pub fn verify(&self, msg: &[u8], signature: &Signature) -> Result<bool> {
+        Ok(Self::recover(msg, signature)? == self.chain_key.1)
+    }
+
+    /// Signs `msg` with this identity's packet (ed25519) key.
+    pub fn sign_packet(&self, msg: &[u8]) -> OffchainSignature {
+        OffchainSignature::sign_message(msg, &self.packet_key.0)
+    }
+
+    /// Encodes this identity as a human-transcribable paper backup: the packet key and chain key
+    /// are each encoded as their own standard BIP-39 24-word phrase (32 bytes of entropy plus the
+    /// standard SHA-256 checksum), concatenated packet-key-first into a single 48-word phrase.
+    ///
+    /// Unlike [`Self::from_mnemonic`], which deterministically derives a fresh identity from an
+    /// arbitrary seed phrase, this is an exact, reversible encoding of the actual private key
+    /// material — recover it with [`Self::from_mnemonic_backup`].
+    pub fn to_mnemonic_backup(&self) -> String {
+        let packet_mnemonic =
+            Mnemonic::from_entropy(&self.packet_key.0).expect("32 bytes is valid BIP-39 entropy");
+        let chain_mnemonic = Mnemonic::from_entropy(&self.chain_key.0).expect("32 bytes is valid BIP-39 entropy");
+
+        format!("{packet_mnemonic} {chain_mnemonic}")
+    }
+
+    /// Reconstructs a [`HoprKeys`] from a backup phrase produced by [`Self::to_mnemonic_backup`],
+    /// validating the checksum embedded in each 24-word half before rederiving the public keys.
+    pub fn from_mnemonic_backup(phrase: &str) -> Result<Self> {
+        let words: Vec<&str> = phrase.split_whitespace().collect();
+
+        if words.len() != 48 {
+            return Err(KeyPairError::InvalidMnemonic {
+                reason: format!("expected 48 words (two 24-word halves), got {}", words.len()),
+            });
+        }
+
+        let packet_phrase = words[..24].join(" ");
+        let chain_phrase = words[24..].join(" ");
+
+        let packet_mnemonic = Mnemonic::parse_in_normalized(Language::English, &packet_phrase)
+            .map_err(|e| KeyPairError::InvalidMnemonic {
+                reason: format!("packet key half: {e}"),
+            })?;
+        let chain_mnemonic = Mnemonic::parse_in_normalized(Language::English, &chain_phrase)
+            .map_err(|e| KeyPairError::InvalidMnemonic {
+                reason: format!("chain key half: {e}"),
+            })?;
+
+        let packet_entropy = packet_mnemonic.to_entropy();
+        let chain_entropy = chain_mnemonic.to_entropy();
+
+        if packet_entropy.len() != PACKET_KEY_LENGTH || chain_entropy.len() != CHAIN_KEY_LENGTH {
+            return Err(KeyPairError::InvalidMnemonic {
+                reason: "each half must encode exactly 32 bytes of entropy".into(),
+            });
+        }
+
+        let mut packet_key = [0u8; PACKET_KEY_LENGTH];
+        packet_key.copy_from_slice(&packet_entropy);
+        let mut chain_key = [0u8; CHAIN_KEY_LENGTH];
+        chain_key.copy_from_slice(&chain_entropy);
+
+        (packet_key, chain_key).try_into()
+    }
+
     pub fn init(opts: IdentityOptions) -> Result<Self> {
         let exists = metadata(&opts.id_path).is_ok();
+        let params = if let Some(true) = opts.use_weak_crypto {
+            KeystoreParams::weak_for_testing()
+        } else {
+            KeystoreParams::interactive()
+        };
 
         if !exists && opts.private_key.is_some() {
             let keys = if let Some(private_key) = opts.private_key {
@@ -236,15 +499,7 @@ impl HoprKeys {
             } else {
                 HoprKeys::new()
             };
-            keys.write_eth_keystore(
-                &opts.id_path,
-                &opts.password,
-                if let Some(true) = opts.use_weak_crypto {
-                    true
-                } else {
-                    false
-                },
-            )?;
+            keys.write_eth_keystore(&opts.id_path, &opts.password, &params)?;
 
             return Ok(keys);
         }
@@ -253,15 +508,7 @@ impl HoprKeys {
             match HoprKeys::read_eth_keystore(&opts.id_path, &opts.password) {
                 Ok((keys, needs_migration)) => {
                     if needs_migration {
-                        keys.write_eth_keystore(
-                            &opts.id_path,
-                            &opts.password,
-                            if let Some(true) = opts.use_weak_crypto {
-                                true
-                            } else {
-                                false
-                            },
-                        )?
+                        keys.write_eth_keystore(&opts.id_path, &opts.password, &params)?
                     }
                     return Ok(keys);
                 }
@@ -273,15 +520,7 @@ impl HoprKeys {
 
         if opts.initialize {
             let keys = HoprKeys::new();
-            keys.write_eth_keystore(
-                &opts.id_path,
-                &opts.password,
-                if let Some(true) = opts.use_weak_crypto {
-                    true
-                } else {
-                    false
-                },
-            )?;
+            keys.write_eth_keystore(&opts.id_path, &opts.password, &params)?;
 
             return Ok(keys);
         }
@@ -294,14 +533,35 @@ impl HoprKeys {
         ))
     }
 
-    /// Reads a keystore file using custom FS operations
+    /// Rotates the password protecting the keystore at `path` in place, preserving the same
+    /// `packet_key`/`chain_key`/`id`. Unlike `--initialize`, which discards the existing identity,
+    /// this decrypts under `old_password` and re-encrypts under `new_password` with a freshly
+    /// generated salt and IV (never reusing the old ones), so a compromised password can be
+    /// rotated without losing the node's identity.
+    pub fn change_password(path: &str, old_password: &str, new_password: &str, use_weak_crypto: bool) -> Result<()> {
+        let (keys, _) = Self::read_eth_keystore(path, old_password)?;
+
+        let params = if use_weak_crypto {
+            KeystoreParams::weak_for_testing()
+        } else {
+            KeystoreParams::interactive()
+        };
+
+        keys.write_eth_keystore(path, new_password, &params)
+    }
+
+    /// Reads a keystore file using custom FS operations.
+    ///
+    /// The returned `bool` signals that the caller should re-encrypt the keystore in place:
+    /// because it is still in the legacy single-key format, because it uses PBKDF2 rather than
+    /// scrypt, or because its scrypt `n` is lower than [`KeystoreParams::interactive`]'s.
     ///
     /// Highly inspired by https://github.com/roynalnaruto/eth-keystore-rs
     pub fn read_eth_keystore(path: &str, password: &str) -> Result<(Self, bool)> {
         let json_string = read_to_string(path)?;
         let keystore: EthKeystore = from_json_string(&json_string)?;
 
-        let key = match keystore.crypto.kdfparams {
+        let (key, weak_kdf) = match keystore.crypto.kdfparams {
             KdfparamsType::Scrypt { dklen, n, p, r, salt } => {
                 let mut key = vec![0u8; dklen as usize];
                 let log_n = (n as f32).log2() as u8;
@@ -309,9 +569,22 @@ impl HoprKeys {
                     .map_err(|err| KeyPairError::KeyDerivationError { err: err.to_string() })?;
                 scrypt(password.as_ref(), &salt, &scrypt_params, key.as_mut_slice())
                     .map_err(|err| KeyPairError::KeyDerivationError { err: err.to_string() })?;
-                key
+                (key, log_n < KeystoreParams::interactive().log_n)
+            }
+            KdfparamsType::Pbkdf2 { c, prf, dklen, salt } => {
+                if prf != "hmac-sha256" {
+                    return Err(KeyPairError::KeyDerivationError {
+                        err: format!("unsupported PBKDF2 PRF '{prf}', only hmac-sha256 is supported"),
+                    });
+                }
+
+                let mut key = vec![0u8; dklen as usize];
+                pbkdf2_hmac::<Sha256>(password.as_bytes(), &salt, c, key.as_mut_slice());
+
+                // Not HOPR's own scrypt format, so re-encrypt into it the next time this
+                // identity is written, the same way the legacy 32-byte format is migrated below.
+                (key, true)
             }
-            _ => panic!("HOPR only supports scrypt"),
         };
 
         // Derive the MAC from the derived key and ciphertext.
@@ -330,19 +603,25 @@ impl HoprKeys {
         let mut pk = keystore.crypto.ciphertext;
 
         match pk.len() {
-            32 => {
+            len if len <= 32 => {
                 decryptor.apply_keystream(&mut pk);
 
                 let mut packet_key = [0u8; PACKET_KEY_LENGTH];
                 getrandom(&mut packet_key)?;
 
-                let mut chain_key = [0u8; 32];
-                chain_key.clone_from_slice(&pk.as_slice()[0..32]);
+                // geth/OpenEthereum keystores may carry a secret shorter than 32 bytes (leading
+                // zero bytes stripped before encryption); left-pad it back out to the full scalar
+                // width rather than rejecting it outright.
+                let mut chain_key = [0u8; CHAIN_KEY_LENGTH];
+                chain_key[CHAIN_KEY_LENGTH - len..].copy_from_slice(&pk);
+
+                let chain_public_key = PublicKey::from_privkey(&chain_key[..])
+                    .map_err(|e| KeyPairError::KeyDerivationError { err: e.to_string() })?;
 
                 Ok((
                     HoprKeys {
                         packet_key: (packet_key, OffchainPublicKey::from_privkey(&packet_key[..]).unwrap()),
-                        chain_key: (chain_key, PublicKey::from_privkey(&chain_key[..]).unwrap()),
+                        chain_key: (chain_key, chain_public_key),
                         id: keystore.id,
                     },
                     true,
@@ -380,7 +659,7 @@ impl HoprKeys {
                         chain_key: (chain_key, PublicKey::from_privkey(&chain_key[..]).unwrap()),
                         id: keystore.id,
                     },
-                    false,
+                    weak_kdf,
                 ))
             }
             _ => {
@@ -392,10 +671,12 @@ impl HoprKeys {
         }
     }
 
-    /// Writes a keystore file using custom FS operation and custom entropy source
+    /// Writes a keystore file using custom FS operation and custom entropy source, deriving the
+    /// encryption key with the cost given by `params` (see [`KeystoreParams::interactive`] and
+    /// [`KeystoreParams::sensitive`]).
     ///
     /// Highly inspired by https://github.com/roynalnaruto/eth-keystore-rs
-    pub fn write_eth_keystore(&self, path: &str, password: &str, use_weak_crypto: bool) -> Result<()> {
+    pub fn write_eth_keystore(&self, path: &str, password: &str, params: &KeystoreParams) -> Result<()> {
         // Generate a random salt.
         let mut salt = [0u8; HOPR_KEY_SIZE];
 
@@ -403,13 +684,8 @@ impl HoprKeys {
 
         // Derive the key.
         let mut key = [0u8; HOPR_KDF_PARAMS_DKLEN as usize];
-        let scrypt_params = ScryptParams::new(
-            if use_weak_crypto { 1 } else { HOPR_KDF_PARAMS_LOG_N },
-            HOPR_KDF_PARAMS_R,
-            HOPR_KDF_PARAMS_P,
-            HOPR_KDF_PARAMS_DKLEN.into(),
-        )
-        .map_err(|e| KeyPairError::KeyDerivationError { err: e.to_string() })?;
+        let scrypt_params = ScryptParams::new(params.log_n, params.r, params.p, HOPR_KDF_PARAMS_DKLEN.into())
+            .map_err(|e| KeyPairError::KeyDerivationError { err: e.to_string() })?;
 
         scrypt(password.as_ref(), &salt, &scrypt_params, key.as_mut_slice())
             .map_err(|e| KeyPairError::KeyDerivationError { err: e.to_string() })?;
@@ -443,9 +719,9 @@ impl HoprKeys {
                 kdf: KdfType::Scrypt,
                 kdfparams: KdfparamsType::Scrypt {
                     dklen: HOPR_KDF_PARAMS_DKLEN,
-                    n: 2u32.pow(if use_weak_crypto { 1 } else { HOPR_KDF_PARAMS_LOG_N } as u32),
-                    p: HOPR_KDF_PARAMS_P,
-                    r: HOPR_KDF_PARAMS_R,
+                    n: params.n(),
+                    p: params.p,
+                    r: params.r,
                     salt: salt.to_vec(),
                 },
                 mac: mac.to_vec(),
@@ -536,7 +812,7 @@ pub mod wasm {
 mod tests {
     use std::fs;
 
-    use super::HoprKeys;
+    use super::{HoprKeys, KeystoreParams};
     use tempfile::tempdir;
     use utils_types::traits::PeerIdLike;
 
@@ -555,8 +831,12 @@ mod tests {
 
         let keys = HoprKeys::new();
 
-        keys.write_eth_keystore(identity_dir.to_str().unwrap(), DEFAULT_PASSWORD, true)
-            .unwrap();
+        keys.write_eth_keystore(
+            identity_dir.to_str().unwrap(),
+            DEFAULT_PASSWORD,
+            &KeystoreParams::interactive(),
+        )
+        .unwrap();
 
         let (deserialized, needs_migration) =
             HoprKeys::read_eth_keystore(identity_dir.to_str().unwrap(), DEFAULT_PASSWORD).unwrap();
@@ -611,4 +891,137 @@ mod tests {
             "16Uiu2HAm8WFpakjrdWauUKq2hb5bejivnbtFAumVv9KHKN5AvXXK"
         );
     }
+
+    #[test]
+    fn read_eth_keystore_flags_below_threshold_kdf_params_for_reencryption() {
+        let tmp = tempdir().unwrap();
+        let identity_dir = tmp.path().join("hopr-unit-test-identity");
+
+        let keys = HoprKeys::new();
+        keys.write_eth_keystore(
+            identity_dir.to_str().unwrap(),
+            DEFAULT_PASSWORD,
+            &KeystoreParams::weak_for_testing(),
+        )
+        .unwrap();
+
+        let (_, needs_migration) =
+            HoprKeys::read_eth_keystore(identity_dir.to_str().unwrap(), DEFAULT_PASSWORD).unwrap();
+        assert!(needs_migration);
+
+        keys.write_eth_keystore(
+            identity_dir.to_str().unwrap(),
+            DEFAULT_PASSWORD,
+            &KeystoreParams::interactive(),
+        )
+        .unwrap();
+
+        let (_, needs_migration) =
+            HoprKeys::read_eth_keystore(identity_dir.to_str().unwrap(), DEFAULT_PASSWORD).unwrap();
+        assert!(!needs_migration);
+    }
+
+    #[test]
+    fn from_mnemonic_is_deterministic() {
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+        let first = HoprKeys::from_mnemonic(phrase).unwrap();
+        let second = HoprKeys::from_mnemonic(phrase).unwrap();
+
+        assert_eq!(first.chain_key.1, second.chain_key.1);
+        assert_eq!(first.packet_key.1, second.packet_key.1);
+    }
+
+    #[test]
+    fn from_mnemonic_rejects_an_invalid_phrase() {
+        assert!(HoprKeys::from_mnemonic("not a valid bip39 mnemonic phrase at all").is_err());
+    }
+
+    #[test]
+    fn recover_identity_finds_the_single_missing_word() {
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let expected = HoprKeys::from_mnemonic(phrase).unwrap().chain_key.1.to_address().to_string();
+
+        let template = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon ?";
+        let recovered = HoprKeys::recover_identity(template, &expected).unwrap();
+
+        assert_eq!(recovered, phrase);
+    }
+
+    #[test]
+    fn sign_chain_should_be_verifiable_and_recoverable() {
+        let keys = HoprKeys::new();
+        let message = b"prove control of this node's on-chain address";
+
+        let signature = keys.sign_chain(message);
+        let address = keys.chain_key.1.to_address().to_string();
+
+        assert!(keys.verify(message, &signature).unwrap());
+        assert!(!keys.verify(b"a different message", &signature).unwrap());
+        assert!(super::verify_address(&address, message, &signature).unwrap());
+
+        let recovered = HoprKeys::recover(message, &signature).unwrap();
+        assert_eq!(recovered, keys.chain_key.1);
+    }
+
+    #[test]
+    fn sign_packet_should_be_verifiable() {
+        let keys = HoprKeys::new();
+        let message = b"prove control of this node's packet key";
+
+        let signature = keys.sign_packet(message);
+
+        assert!(keys.packet_key.1.verify(message, &signature));
+    }
+
+    #[test]
+    fn mnemonic_backup_round_trips() {
+        let keys = HoprKeys::new();
+
+        let backup = keys.to_mnemonic_backup();
+        let recovered = HoprKeys::from_mnemonic_backup(&backup).unwrap();
+
+        assert_eq!(recovered, keys);
+    }
+
+    #[test]
+    fn from_mnemonic_backup_rejects_wrong_word_count() {
+        assert!(HoprKeys::from_mnemonic_backup("abandon abandon abandon").is_err());
+    }
+
+    #[test]
+    fn from_mnemonic_backup_rejects_bad_checksum() {
+        let keys = HoprKeys::new();
+        let backup = keys.to_mnemonic_backup();
+        let mut words: Vec<&str> = backup.split_whitespace().collect();
+        // Swap the last (checksum-bearing) word of the packet-key half for an arbitrary other
+        // wordlist entry, which breaks the embedded checksum without changing the word count.
+        words[23] = if words[23] == "abandon" { "zoo" } else { "abandon" };
+        let corrupted = words.join(" ");
+
+        assert!(HoprKeys::from_mnemonic_backup(&corrupted).is_err());
+    }
+
+    #[test]
+    fn change_password_preserves_identity_under_the_new_password() {
+        let tmp = tempdir().unwrap();
+        let identity_dir = tmp.path().join("hopr-unit-test-identity");
+
+        let keys = HoprKeys::new();
+        keys.write_eth_keystore(
+            identity_dir.to_str().unwrap(),
+            DEFAULT_PASSWORD,
+            &KeystoreParams::weak_for_testing(),
+        )
+        .unwrap();
+
+        let new_password = "a different password";
+        HoprKeys::change_password(identity_dir.to_str().unwrap(), DEFAULT_PASSWORD, new_password, true).unwrap();
+
+        assert!(HoprKeys::read_eth_keystore(identity_dir.to_str().unwrap(), DEFAULT_PASSWORD).is_err());
+
+        let (deserialized, _) =
+            HoprKeys::read_eth_keystore(identity_dir.to_str().unwrap(), new_password).unwrap();
+        assert_eq!(deserialized, keys);
+    }
 }
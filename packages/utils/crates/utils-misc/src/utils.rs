@@ -2,6 +2,7 @@ use real_base::error::RealError;
 use real_base::error::RealError::GeneralError;
 use real_base::real;
 use serde::Deserialize;
+use std::path::PathBuf;
 
 /// Serialization structure for package.json
 #[derive(Deserialize)]
@@ -19,6 +20,164 @@ pub fn get_package_version(package_file: &str) -> Result<String, RealError> {
     }
 }
 
+/// Recursively walks `root` (as the npm-prefetch tooling does) and returns the `version` of
+/// every `package.json` found, paired with its path (`root` joined with the file's location
+/// below it). `node_modules`
+/// directories are skipped, since their nested `package.json` files describe installed
+/// dependencies rather than workspace packages. Results are sorted by path so callers get a
+/// stable, deterministic report across runs.
+pub fn collect_workspace_versions(root: &str) -> Result<Vec<(String, String)>, RealError> {
+    let mut versions = Vec::new();
+    let mut pending = vec![PathBuf::from(root)];
+
+    while let Some(dir) = pending.pop() {
+        let entries = std::fs::read_dir(&dir).map_err(|e| GeneralError(e.to_string()))?;
+
+        for entry in entries {
+            let path = entry.map_err(|e| GeneralError(e.to_string()))?.path();
+
+            if path.is_dir() {
+                if path.file_name().and_then(|n| n.to_str()) != Some("node_modules") {
+                    pending.push(path);
+                }
+                continue;
+            }
+
+            if path.file_name().and_then(|n| n.to_str()) == Some("package.json") {
+                let path_str = path
+                    .to_str()
+                    .ok_or_else(|| GeneralError(format!("non-utf8 path: {}", path.to_string_lossy())))?;
+                versions.push((path_str.to_owned(), get_package_version(path_str)?));
+            }
+        }
+    }
+
+    versions.sort();
+    Ok(versions)
+}
+
+/// One dependency whose `version` and/or `integrity` (the SHA-512 subresource integrity hash)
+/// disagree between `package-lock.json`'s legacy `dependencies` map and its `packages` map,
+/// which both describe the same installed tree in lockfile v2/v3 for backward compatibility.
+#[derive(Debug, PartialEq, Eq, serde::Serialize)]
+pub struct LockfileMismatch {
+    pub package: String,
+    pub dependencies_version: Option<String>,
+    pub packages_version: Option<String>,
+    pub dependencies_integrity: Option<String>,
+    pub packages_integrity: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct LockfileEntry {
+    version: Option<String>,
+    integrity: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct PackageLockFile {
+    #[serde(default)]
+    dependencies: std::collections::BTreeMap<String, LockfileEntry>,
+    #[serde(default)]
+    packages: std::collections::BTreeMap<String, LockfileEntry>,
+}
+
+/// Cross-checks `package-lock.json`'s legacy `dependencies` map against its `packages` map and
+/// reports every package whose recorded `version` or `integrity` disagrees between the two,
+/// rather than failing on the first discrepancy. An empty report means the lockfile is
+/// internally consistent. This lets the release path catch a tampered or drifted lockfile the
+/// same way the prefetch tool's `--fixup-lockfile` step normalizes one.
+pub fn verify_lockfile(package_lock_path: &str) -> Result<Vec<LockfileMismatch>, RealError> {
+    let file_data = real::read_file(package_lock_path)?;
+    let lockfile: PackageLockFile = serde_json::from_slice(&file_data).map_err(|e| GeneralError(e.to_string()))?;
+
+    let mut mismatches = Vec::new();
+
+    for (name, dep) in lockfile.dependencies.iter() {
+        // `packages` entries are keyed by install path (e.g. `node_modules/foo`), `dependencies`
+        // entries by bare package name; the install path for a top-level dependency is the one
+        // `node_modules/<name>` suffix shared by both maps.
+        let packages_key = format!("node_modules/{name}");
+        let Some(pkg) = lockfile.packages.get(&packages_key) else {
+            continue;
+        };
+
+        if dep.version != pkg.version || dep.integrity != pkg.integrity {
+            mismatches.push(LockfileMismatch {
+                package: name.clone(),
+                dependencies_version: dep.version.clone(),
+                packages_version: pkg.version.clone(),
+                dependencies_integrity: dep.integrity.clone(),
+                packages_integrity: pkg.integrity.clone(),
+            });
+        }
+    }
+
+    Ok(mismatches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Writes `contents` to a uniquely-named file under the OS temp dir and returns its path,
+    /// so `verify_lockfile` has a real path to read without needing a fixture file on disk.
+    fn write_temp_lockfile(name: &str, contents: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("utils-misc-test-{name}-{}.json", std::process::id()));
+        std::fs::write(&path, contents).expect("failed to write temp lockfile");
+        path
+    }
+
+    #[test]
+    fn verify_lockfile_should_report_a_mismatched_integrity_hash() {
+        let path = write_temp_lockfile(
+            "mismatched-integrity",
+            r#"{
+                "dependencies": {
+                    "left-pad": { "version": "1.0.0", "integrity": "sha512-AAAA" }
+                },
+                "packages": {
+                    "node_modules/left-pad": { "version": "1.0.0", "integrity": "sha512-BBBB" }
+                }
+            }"#,
+        );
+
+        let mismatches = verify_lockfile(path.to_str().unwrap()).expect("verify_lockfile should succeed");
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(
+            mismatches,
+            vec![LockfileMismatch {
+                package: "left-pad".to_owned(),
+                dependencies_version: Some("1.0.0".to_owned()),
+                packages_version: Some("1.0.0".to_owned()),
+                dependencies_integrity: Some("sha512-AAAA".to_owned()),
+                packages_integrity: Some("sha512-BBBB".to_owned()),
+            }]
+        );
+    }
+
+    #[test]
+    fn verify_lockfile_should_ignore_a_dependency_missing_from_packages() {
+        let path = write_temp_lockfile(
+            "missing-from-packages",
+            r#"{
+                "dependencies": {
+                    "orphaned-dep": { "version": "2.0.0", "integrity": "sha512-CCCC" }
+                },
+                "packages": {}
+            }"#,
+        );
+
+        let mismatches = verify_lockfile(path.to_str().unwrap()).expect("verify_lockfile should succeed");
+        std::fs::remove_file(&path).ok();
+
+        // `dependencies` entries with no `node_modules/<name>` counterpart in `packages` have
+        // nothing to cross-check against, so they're skipped rather than reported as mismatches.
+        assert!(mismatches.is_empty());
+    }
+}
+
 #[cfg(feature = "wasm")]
 pub mod wasm {
     use crate::ok_or_jserr;
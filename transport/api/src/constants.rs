@@ -21,11 +21,38 @@ pub(crate) const RESERVED_SESSION_TAG_UPPER_LIMIT: u16 = 1024;
 /// specifically dedicated for the internal use by the subprotocols.
 pub(crate) const RESERVED_SUBPROTOCOL_TAG_UPPER_LIMIT: u16 = 16;
 
+/// The application tag carrying Start-protocol session-initiation handshake messages
+/// (SYN/SYN-ACK/reject), taken from the subprotocol reserved tag range so it is never handed out
+/// as a session's own application tag.
+pub(crate) const SESSION_INITIATION_TAG: u16 = 1;
+
 /// Time within Start protocol must finish session initiation.
 pub(crate) const SESSION_INITIATION_TIMEOUT: Duration = Duration::from_secs(60);
 
-/// Maximum lifetime of an idle session.
+/// How long an idle session is left alone before [`crate::SessionVisibility`] marks it suspended,
+/// pausing its keepalive/cover traffic, rather than it being torn down outright.
 pub(crate) const SESSION_LIFETIME: Duration = Duration::from_secs(2 * 60);
 
+/// Hard cutoff for a session that has been idle this long in total, counting the
+/// [`SESSION_LIFETIME`] before it became [`crate::SessionVisibility::Suspended`]: it is
+/// torn down and pruned from the session cache rather than waiting any longer for a resume.
+pub(crate) const SESSION_HARD_EXPIRE_AFTER: Duration = Duration::from_secs(5 * 60);
+
+/// How often [`HoprTransportProcess::SessionVisibilitySweep`] checks every open session's idle time
+/// against [`SESSION_LIFETIME`] to apply [`crate::SessionVisibility::Suspended`].
+pub(crate) const SESSION_VISIBILITY_SWEEP_INTERVAL: Duration = Duration::from_secs(15);
+
+/// How long the DCUtR-style simultaneous-open dial is allowed to take before the hole-punch
+/// attempt is abandoned and the two peers keep talking over the existing relayed connection.
+pub(crate) const HOLE_PUNCH_TIMEOUT: Duration = Duration::from_secs(10);
+
 /// The first challenge value used in Start protocol to initiate a session.
 pub(crate) const MIN_CHALLENGE: StartChallenge = 1;
+
+/// A peer that has not been seen for this long is considered expired: [`Network::sweep_expired_peers`]
+/// emits [`NetworkEvent::PeerExpired`] for it and prunes it from the network store.
+pub(crate) const PEER_EXPIRY_TTL: Duration = Duration::from_secs(5 * 60);
+
+/// How often the background sweeper spawned as [`HoprTransportProcess::NetworkExpiry`] calls
+/// [`Network::sweep_expired_peers`].
+pub(crate) const PEER_EXPIRY_SWEEP_INTERVAL: Duration = Duration::from_secs(60);
@@ -0,0 +1,295 @@
+//! Resolution of `/dnsaddr/<name>` bootstrap-style multiaddrs into concrete transport addresses.
+//!
+//! A lookup of the TXT record `_dnsaddr.<name>` returns zero or more `dnsaddr=<multiaddr>`
+//! entries; each entry is either a concrete address or another `/dnsaddr/...` record to follow.
+//! [`resolve_dnsaddr`] follows the latter recursively (bounded by [`DNSADDR_MAX_DEPTH`] and a
+//! cycle guard) and leaves anything that isn't a dnsaddr entry untouched.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use multiaddr::{Multiaddr, Protocol};
+
+/// How many `/dnsaddr/...` hops [`resolve_dnsaddr`] will follow before giving up, so a
+/// misconfigured (or malicious) zone chaining dnsaddr records into itself cannot hang resolution.
+const DNSADDR_MAX_DEPTH: usize = 8;
+
+/// Abstracts the DNS TXT lookup [`resolve_dnsaddr`] needs, so resolution can be driven
+/// deterministically in tests instead of hitting a real resolver.
+#[async_trait::async_trait]
+pub trait TxtLookup: Send + Sync {
+    /// Returns every TXT record string for `name`, each alongside that record's own TTL.
+    async fn lookup_txt(&self, name: &str) -> std::io::Result<Vec<(String, Duration)>>;
+}
+
+/// The default [`TxtLookup`], backed by a real DNS resolver.
+#[derive(Clone)]
+pub struct NativeTxtLookup(std::sync::Arc<trust_dns_resolver::TokioAsyncResolver>);
+
+impl NativeTxtLookup {
+    /// Builds a resolver from the OS's own DNS configuration (`/etc/resolv.conf` and friends).
+    pub fn from_system_conf() -> std::io::Result<Self> {
+        let (cfg, opts) = trust_dns_resolver::system_conf::read_system_conf()?;
+        Ok(Self(std::sync::Arc::new(trust_dns_resolver::TokioAsyncResolver::tokio(
+            cfg, opts,
+        ))))
+    }
+}
+
+impl Default for NativeTxtLookup {
+    fn default() -> Self {
+        Self(std::sync::Arc::new(trust_dns_resolver::TokioAsyncResolver::tokio(
+            trust_dns_resolver::config::ResolverConfig::default(),
+            trust_dns_resolver::config::ResolverOpts::default(),
+        )))
+    }
+}
+
+#[async_trait::async_trait]
+impl TxtLookup for NativeTxtLookup {
+    async fn lookup_txt(&self, name: &str) -> std::io::Result<Vec<(String, Duration)>> {
+        let lookup = self
+            .0
+            .txt_lookup(name)
+            .await
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+
+        Ok(lookup
+            .as_lookup()
+            .records()
+            .iter()
+            .filter_map(|record| {
+                let ttl = Duration::from_secs(record.ttl() as u64);
+                record.data().and_then(|d| d.as_txt()).map(|txt| (txt.to_string(), ttl))
+            })
+            .collect())
+    }
+}
+
+#[derive(Clone)]
+struct CachedEntry {
+    addrs: Vec<Multiaddr>,
+    expires_at: Instant,
+}
+
+/// TTL-bounded cache of previously resolved `/dnsaddr/<name>` entries, keyed by `name`, honoring
+/// the minimum TTL of the TXT records that produced each entry so `announceable_multiaddresses()`
+/// doesn't re-resolve on every call yet still picks up changes once the DNS record expires.
+#[derive(Default)]
+pub struct DnsaddrCache {
+    entries: Mutex<HashMap<String, CachedEntry>>,
+}
+
+impl DnsaddrCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn get(&self, name: &str) -> Option<Vec<Multiaddr>> {
+        let entries = self.entries.lock().unwrap();
+        entries
+            .get(name)
+            .filter(|entry| entry.expires_at > Instant::now())
+            .map(|entry| entry.addrs.clone())
+    }
+
+    fn put(&self, name: String, addrs: Vec<Multiaddr>, ttl: Duration) {
+        self.entries.lock().unwrap().insert(
+            name,
+            CachedEntry {
+                addrs,
+                expires_at: Instant::now() + ttl,
+            },
+        );
+    }
+}
+
+/// The name being resolved if `ma` is a `/dnsaddr/<name>` multiaddr, or `None` if it isn't.
+fn dnsaddr_name(ma: &Multiaddr) -> Option<String> {
+    match ma.iter().next()? {
+        Protocol::Dnsaddr(name) => Some(name.to_string()),
+        _ => None,
+    }
+}
+
+/// Expands `ma` into its set of concrete multiaddrs if it is a `/dnsaddr/<name>` entry, following
+/// nested dnsaddr records up to [`DNSADDR_MAX_DEPTH`] deep and skipping any name already visited
+/// to guard against a cycle, or returns `ma` unchanged if it isn't a dnsaddr entry to begin with.
+/// Results for a given name are served out of `cache` until the TXT record's own TTL elapses.
+pub async fn resolve_dnsaddr(ma: &Multiaddr, resolver: &dyn TxtLookup, cache: &DnsaddrCache) -> Vec<Multiaddr> {
+    let Some(root) = dnsaddr_name(ma) else {
+        return vec![ma.clone()];
+    };
+
+    let mut visited = HashSet::new();
+    let mut queue = vec![(root, 0usize)];
+    let mut resolved = Vec::new();
+
+    while let Some((name, depth)) = queue.pop() {
+        if depth >= DNSADDR_MAX_DEPTH || !visited.insert(name.clone()) {
+            continue;
+        }
+
+        if let Some(cached) = cache.get(&name) {
+            for entry_ma in cached {
+                match dnsaddr_name(&entry_ma) {
+                    Some(nested) => queue.push((nested, depth + 1)),
+                    None => resolved.push(entry_ma),
+                }
+            }
+            continue;
+        }
+
+        let records = match resolver.lookup_txt(&format!("_dnsaddr.{name}")).await {
+            Ok(records) => records,
+            Err(e) => {
+                tracing::debug!(name = name.as_str(), "dnsaddr TXT lookup failed: {e}");
+                continue;
+            }
+        };
+
+        let mut this_level = Vec::new();
+        let mut entries = Vec::new();
+        let mut min_ttl = Duration::MAX;
+
+        for (txt, ttl) in records {
+            min_ttl = min_ttl.min(ttl);
+
+            let Some(entry) = txt.strip_prefix("dnsaddr=") else {
+                continue;
+            };
+
+            let Ok(entry_ma) = entry.parse::<Multiaddr>() else {
+                continue;
+            };
+
+            match dnsaddr_name(&entry_ma) {
+                Some(nested) => queue.push((nested, depth + 1)),
+                None => this_level.push(entry_ma.clone()),
+            }
+            entries.push(entry_ma);
+        }
+
+        if min_ttl != Duration::MAX {
+            cache.put(name.clone(), entries, min_ttl);
+        }
+
+        resolved.extend(this_level);
+    }
+
+    resolved
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct MockTxtLookup {
+        records: HashMap<String, Vec<(String, Duration)>>,
+    }
+
+    #[async_trait::async_trait]
+    impl TxtLookup for MockTxtLookup {
+        async fn lookup_txt(&self, name: &str) -> std::io::Result<Vec<(String, Duration)>> {
+            Ok(self.records.get(name).cloned().unwrap_or_default())
+        }
+    }
+
+    #[async_std::test]
+    async fn non_dnsaddr_multiaddr_is_returned_unchanged() {
+        let ma: Multiaddr = "/ip4/1.2.3.4/tcp/9091".parse().unwrap();
+        let resolver = MockTxtLookup::default();
+        let cache = DnsaddrCache::new();
+
+        assert_eq!(resolve_dnsaddr(&ma, &resolver, &cache).await, vec![ma]);
+    }
+
+    #[async_std::test]
+    async fn dnsaddr_entry_is_expanded_from_txt_records() {
+        let mut records = HashMap::new();
+        records.insert(
+            "_dnsaddr.bootstrap.hoprnet.org".to_string(),
+            vec![(
+                "dnsaddr=/ip4/1.2.3.4/tcp/9091/p2p/16Uiu2HAmP5z6X3".to_string(),
+                Duration::from_secs(60),
+            )],
+        );
+        let resolver = MockTxtLookup { records };
+        let cache = DnsaddrCache::new();
+
+        let ma: Multiaddr = "/dnsaddr/bootstrap.hoprnet.org".parse().unwrap();
+        let resolved = resolve_dnsaddr(&ma, &resolver, &cache).await;
+
+        assert_eq!(
+            resolved,
+            vec!["/ip4/1.2.3.4/tcp/9091/p2p/16Uiu2HAmP5z6X3".parse::<Multiaddr>().unwrap()]
+        );
+    }
+
+    #[async_std::test]
+    async fn nested_dnsaddr_entries_are_followed() {
+        let mut records = HashMap::new();
+        records.insert(
+            "_dnsaddr.outer.hoprnet.org".to_string(),
+            vec![("dnsaddr=/dnsaddr/inner.hoprnet.org".to_string(), Duration::from_secs(60))],
+        );
+        records.insert(
+            "_dnsaddr.inner.hoprnet.org".to_string(),
+            vec![(
+                "dnsaddr=/ip4/5.6.7.8/tcp/9091/p2p/16Uiu2HAmP5z6X3".to_string(),
+                Duration::from_secs(60),
+            )],
+        );
+        let resolver = MockTxtLookup { records };
+        let cache = DnsaddrCache::new();
+
+        let ma: Multiaddr = "/dnsaddr/outer.hoprnet.org".parse().unwrap();
+        let resolved = resolve_dnsaddr(&ma, &resolver, &cache).await;
+
+        assert_eq!(
+            resolved,
+            vec!["/ip4/5.6.7.8/tcp/9091/p2p/16Uiu2HAmP5z6X3".parse::<Multiaddr>().unwrap()]
+        );
+    }
+
+    #[async_std::test]
+    async fn cyclical_dnsaddr_entries_do_not_hang() {
+        let mut records = HashMap::new();
+        records.insert(
+            "_dnsaddr.a.hoprnet.org".to_string(),
+            vec![("dnsaddr=/dnsaddr/b.hoprnet.org".to_string(), Duration::from_secs(60))],
+        );
+        records.insert(
+            "_dnsaddr.b.hoprnet.org".to_string(),
+            vec![("dnsaddr=/dnsaddr/a.hoprnet.org".to_string(), Duration::from_secs(60))],
+        );
+        let resolver = MockTxtLookup { records };
+        let cache = DnsaddrCache::new();
+
+        let ma: Multiaddr = "/dnsaddr/a.hoprnet.org".parse().unwrap();
+        let resolved = resolve_dnsaddr(&ma, &resolver, &cache).await;
+
+        assert!(resolved.is_empty());
+    }
+
+    #[async_std::test]
+    async fn cached_result_is_reused_without_another_lookup() {
+        let ma_name = "bootstrap.hoprnet.org";
+        let cache = DnsaddrCache::new();
+        cache.put(
+            ma_name.to_string(),
+            vec!["/ip4/9.9.9.9/tcp/9091".parse().unwrap()],
+            Duration::from_secs(60),
+        );
+
+        // An empty resolver would return nothing for a real lookup, so a populated result here
+        // can only have come from the cache.
+        let resolver = MockTxtLookup::default();
+        let ma: Multiaddr = format!("/dnsaddr/{ma_name}").parse().unwrap();
+        let resolved = resolve_dnsaddr(&ma, &resolver, &cache).await;
+
+        assert_eq!(resolved, vec!["/ip4/9.9.9.9/tcp/9091".parse::<Multiaddr>().unwrap()]);
+    }
+}
@@ -14,24 +14,76 @@
 pub mod config;
 /// Constants used and exposed by the crate.
 pub mod constants;
+/// Resolution of `/dnsaddr/...` multiaddrs used by [`HoprTransport::announceable_multiaddresses`].
+pub mod dnsaddr;
 /// Errors used by the crate.
 pub mod errors;
 pub mod helpers;
 pub mod network_notifier;
+pub mod timer;
+pub mod timer_wheel;
 
 use std::{
-    collections::HashMap,
-    sync::{Arc, OnceLock},
+    collections::{BTreeMap, HashMap},
+    sync::{
+        atomic::{AtomicBool, AtomicU32, AtomicU64},
+        Arc, Mutex, OnceLock,
+    },
 };
 
 use async_lock::RwLock;
 use futures::{
-    channel::mpsc::{UnboundedReceiver, UnboundedSender},
+    channel::{
+        mpsc::{UnboundedReceiver, UnboundedSender},
+        oneshot,
+    },
     future::{select, Either},
     pin_mut, FutureExt, StreamExt,
 };
+use serde::{Deserialize, Serialize};
 use tracing::{debug, error, info, trace, warn};
 
+use hopr_transport_session::initiation::StartChallenge;
+
+#[cfg(all(feature = "prometheus", not(test)))]
+use hopr_metrics::metrics::{MultiCounter, SimpleCounter, SimpleGauge, SimpleHistogram};
+
+/// Per-[`HoprTransportProcess`] and session/ticket-aggregation metrics, opted into via the
+/// `prometheus` feature like the rest of the codebase (see `transport/network`'s
+/// `METRIC_NETWORK_HEALTH` and friends). Each subsystem below updates only the metrics it owns,
+/// so a registry scrape reflects whatever subset of the transport is actually wired up.
+#[cfg(all(feature = "prometheus", not(test)))]
+lazy_static::lazy_static! {
+    /// Active entries in the `sessions` cache, refreshed whenever a SYN/SYN-ACK handshake inserts a
+    /// session; a session inserted on its first incoming data does not refresh it.
+    static ref METRIC_ACTIVE_SESSIONS: SimpleGauge =
+        SimpleGauge::new("hopr_transport_active_sessions", "Number of currently open sessions").unwrap();
+    /// Sessions that completed the SYN/SYN-ACK handshake and were inserted into `sessions`.
+    static ref METRIC_SESSIONS_OPENED: SimpleCounter =
+        SimpleCounter::new("hopr_transport_sessions_opened_total", "Number of sessions successfully negotiated").unwrap();
+    /// How many counter-proposals a session-initiation handshake needed before a free tag was agreed.
+    static ref METRIC_SESSION_TAG_RETRIES: SimpleHistogram = SimpleHistogram::new(
+        "hopr_transport_session_tag_retries",
+        "Number of counter-proposals needed to agree on a free session tag",
+        vec![0.0, 1.0, 2.0, 3.0, 5.0, 10.0],
+    ).unwrap();
+    /// Peers currently tracked as connected, refreshed on every `network_connected_peers` call.
+    static ref METRIC_SWARM_CONNECTED_PEERS: SimpleGauge =
+        SimpleGauge::new("hopr_transport_connected_peers", "Number of peers currently tracked as connected").unwrap();
+    /// Application-layer messages handed to the swarm for sending.
+    static ref METRIC_MESSAGES_OUT: SimpleCounter =
+        SimpleCounter::new("hopr_transport_messages_out_total", "Number of application-layer messages sent").unwrap();
+    /// Application-layer messages received off the msg-ack protocol stack.
+    static ref METRIC_MESSAGES_IN: SimpleCounter =
+        SimpleCounter::new("hopr_transport_messages_in_total", "Number of application-layer messages received").unwrap();
+    /// Outcomes of ticket aggregation requests made through [`TicketAggregatorProxy`].
+    static ref METRIC_TICKET_AGGREGATIONS: MultiCounter = MultiCounter::new(
+        "hopr_transport_ticket_aggregations_total",
+        "Outcomes of ticket aggregation requests",
+        &["outcome"],
+    ).unwrap();
+}
+
 use core_network::{
     heartbeat::Heartbeat,
     ping::{PingConfig, PingQueryReplier, Pinger, Pinging},
@@ -58,7 +110,9 @@ use hopr_transport_protocol::{
     },
 };
 pub use {
-    core_network::network::{Health, Network, NetworkTriggeredEvent, PeerOrigin, PeerStatus},
+    core_network::network::{
+        AddressSource, ConnectionDirection, Health, Network, NetworkEvent, NetworkTriggeredEvent, PeerOrigin, PeerStatus,
+    },
     hopr_crypto_types::{
         keypairs::{ChainKeypair, Keypair, OffchainKeypair},
         types::{HalfKeyChallenge, Hash, OffchainPublicKey},
@@ -82,16 +136,267 @@ use crate::{
 
 pub use crate::helpers::{IndexerTransportEvent, PeerEligibility, TicketStatistics};
 
+/// Describes the kind of session an initiator wants, so [`HoprTransport::is_session_supported`]
+/// can answer before a full [`constants::SESSION_INITIATION_TIMEOUT`]-bounded handshake is even
+/// attempted. Sent as [`SessionInitiationMessage::CapabilityQuery`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SessionMode {
+    /// Whether the initiator wants a reliable, retransmitting bidirectional [`Session`] as opposed
+    /// to a best-effort [`HoprTransport::open_response_stream`].
+    pub reliable: bool,
+    /// Largest payload the initiator intends to send in a single segment.
+    pub max_segment_size: usize,
+    /// The reserved-tag class the initiator wants its session tag allocated from; must stay below
+    /// [`constants::RESERVED_SESSION_TAG_UPPER_LIMIT`] and at or above
+    /// [`constants::RESERVED_SUBPROTOCOL_TAG_UPPER_LIMIT`].
+    pub tag_class_limit: u16,
+}
+
+/// What [`HoprTransport::is_session_supported`] reports back: whether the responder can serve
+/// `mode`, and the capability set it would negotiate for a session opened under it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionCapabilityQuery {
+    pub supported: bool,
+    pub capabilities: Vec<SessionCapability>,
+}
+
+/// Lifecycle events emitted over [`HoprTransport::subscribe_session_events`], modeled on the WebXR
+/// `XRSessionEvent`/`ondevicechange` pattern: instead of a session silently vanishing once it
+/// crosses [`constants::SESSION_LIFETIME`], or a caller only learning about a handshake timeout via
+/// the [`errors::HoprTransportError`] returned from [`HoprTransport::new_session`], consumers can
+/// subscribe to this stream and react (rebuild proactively, surface metrics, alert an operator).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SessionLifecycleEvent {
+    /// A SYN/SYN-ACK handshake with `peer` is about to be started.
+    Initiating { peer: PeerId },
+    /// The handshake completed and `id` is now a live session.
+    Established { id: SessionId, tag: u16, peer: PeerId },
+    /// `id` has gone quiet long enough that it is one step away from being pruned.
+    Idle(SessionId),
+    /// `id` sat idle past [`constants::SESSION_HARD_EXPIRE_AFTER`] and was pruned from the session cache.
+    Expired(SessionId),
+    /// `id` was torn down for a reason other than idle expiry (e.g. closed explicitly, or evicted
+    /// to make room under `max_capacity`).
+    Ended { id: SessionId, reason: String },
+    /// The remote end of `id` appears to be reachable over a different set of addresses than
+    /// before, as observed via [`NetworkEvent::MultiaddrsUpdated`].
+    PeerDeviceChanged(SessionId),
+}
+
+/// Fans `event` out to every live [`HoprTransport::subscribe_session_events`] subscriber, dropping
+/// any whose receiver has gone away. A free function over an explicit handle (rather than `&self`)
+/// so it can be called both from [`HoprTransport`] methods and from contexts that only hold a clone
+/// of the subscriber list, such as the `sessions` cache's eviction listener and the spawned
+/// `SessionsManagement`/`SessionPeerWatch` tasks.
+fn emit_lifecycle_event(subscribers: &Mutex<Vec<UnboundedSender<SessionLifecycleEvent>>>, event: SessionLifecycleEvent) {
+    subscribers.lock().unwrap().retain(|tx| tx.unbounded_send(event.clone()).is_ok());
+}
+
+/// Graded alternative to a flat alive/gone session lifetime, modeled on WebXR's `visibilityState`.
+/// A session starts (and stays) [`Self::Visible`] as long as traffic keeps flowing through it; once
+/// neglected past [`SessionVisibilityThresholds::suspend_after`] it becomes [`Self::Suspended`]
+/// ([`HoprTransportProcess::SessionVisibilitySweep`] emits [`SessionLifecycleEvent::Idle`] for the
+/// transition), which only pauses its keepalive/cover traffic rather than tearing it down: a single
+/// subsequent packet flips it straight back to `Visible` without a fresh Start handshake. Only past
+/// [`SessionVisibilityThresholds::hard_expire_after`] does the `sessions` cache's own idle eviction
+/// (see its `eviction_listener`) actually prune it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionVisibility {
+    Visible,
+    Suspended,
+}
+
+/// Thresholds driving [`SessionVisibility`] transitions. Defaults to [`constants::SESSION_LIFETIME`]
+/// and [`constants::SESSION_HARD_EXPIRE_AFTER`]; kept as its own type (rather than bare constants)
+/// so a future per-session `SessionConfig` can override them without changing the state machine.
+#[derive(Debug, Clone, Copy)]
+pub struct SessionVisibilityThresholds {
+    pub suspend_after: std::time::Duration,
+    pub hard_expire_after: std::time::Duration,
+}
+
+impl Default for SessionVisibilityThresholds {
+    fn default() -> Self {
+        Self {
+            suspend_after: constants::SESSION_LIFETIME,
+            hard_expire_after: constants::SESSION_HARD_EXPIRE_AFTER,
+        }
+    }
+}
+
+/// Wire messages of the Start-protocol session-initiation handshake sent on
+/// [`constants::SESSION_INITIATION_TAG`]. The initiator sends [`Self::Syn`] proposing a tag and
+/// capabilities, or [`Self::SynStream`] to open a one-directional [response stream][ResponseStreamFrame]
+/// instead of a bidirectional session; either way the responder confirms the tag via
+/// [`Self::SynAck`], counter-proposes a free one the same way, or gives up with [`Self::Reject`].
+/// Only once a `SynAck` is observed is the tag actually inserted into `sessions` or
+/// `response_streams` on either side. [`Self::CapabilityQuery`]/[`Self::CapabilityReply`] are a
+/// separate, lighter-weight round-trip that never allocates a tag: they let
+/// [`HoprTransport::is_session_supported`] ask whether a [`SessionMode`] would be accepted without
+/// waiting out the full handshake timeout.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum SessionInitiationMessage {
+    Syn {
+        challenge: StartChallenge,
+        proposed_tag: u16,
+        capabilities: Vec<SessionCapability>,
+    },
+    SynStream {
+        challenge: StartChallenge,
+        proposed_tag: u16,
+    },
+    SynAck {
+        challenge: StartChallenge,
+        agreed_tag: u16,
+        capabilities: Vec<SessionCapability>,
+    },
+    Reject {
+        challenge: StartChallenge,
+        reason: String,
+    },
+    CapabilityQuery {
+        challenge: StartChallenge,
+        mode: SessionMode,
+    },
+    CapabilityReply {
+        challenge: StartChallenge,
+        supported: bool,
+        capabilities: Vec<SessionCapability>,
+    },
+}
+
+/// Per-frame-sequenced payload of a [`HoprTransport::open_response_stream`] stream, sent on the
+/// stream's own negotiated tag (never [`constants::SESSION_INITIATION_TAG`]). Frames can arrive
+/// out of order over the mixnet, so each carries its own `seq`; [`ReorderState`] reassembles them
+/// into order before they reach the caller's `Stream`, and [`Self::End`] marks where that ordered
+/// sequence stops.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum ResponseStreamFrame {
+    Data { seq: u32, payload: Box<[u8]> },
+    End { seq: u32 },
+}
+
+/// Bounded number of out-of-order [`ResponseStreamFrame::Data`] frames a stream holds onto while
+/// waiting for the missing predecessor to arrive. Frames that would exceed it are dropped rather
+/// than buffered without limit, so the reassembly buffer cannot grow forever. Only frames held
+/// in that buffer count: in-order payloads go straight to the caller's unbounded channel whether
+/// or not earlier ones have been consumed, so a response stream applies no backpressure.
+const RESPONSE_STREAM_WINDOW: usize = 64;
+
+/// Reassembly state backing one [`ResponseStreamSink`]. Frames are released to the caller strictly
+/// in `seq` order: anything that arrives ahead of `next_seq` sits in `pending` until the gap
+/// closes, and `ended_at` records the `seq` carried by the stream's `End` frame once observed, so
+/// delivery can tell when every payload up to it has actually been forwarded.
+#[derive(Debug, Default)]
+struct ReorderState {
+    next_seq: u32,
+    pending: BTreeMap<u32, Box<[u8]>>,
+    ended_at: Option<u32>,
+}
+
+/// The requester-side registration for one in-flight [`HoprTransport::open_response_stream`] call.
+/// The `SessionsManagement` task looks this up by [`SessionId`] as a fallback when a tag is not a
+/// known bidirectional session, reassembles incoming frames via `reorder`, and forwards payloads
+/// to the caller's `Stream` through `tx`.
+#[derive(Clone)]
+struct ResponseStreamSink {
+    tx: UnboundedSender<Box<[u8]>>,
+    reorder: Arc<Mutex<ReorderState>>,
+}
+
+/// Feeds one `frame` into `sink`'s reassembly state and forwards as many now-in-order payloads to
+/// the caller's `Stream` as have become available. Returns `true` once the stream's `End` frame has
+/// been observed and every payload up to it has been delivered, which is the signal for the caller
+/// to drop `sink` from the `response_streams` cache.
+fn ingest_response_stream_frame(sink: &ResponseStreamSink, frame: ResponseStreamFrame) -> bool {
+    let mut state = sink.reorder.lock().unwrap();
+
+    match frame {
+        ResponseStreamFrame::Data { seq, payload } => {
+            if seq >= state.next_seq && state.pending.len() < RESPONSE_STREAM_WINDOW {
+                state.pending.insert(seq, payload);
+            }
+        }
+        ResponseStreamFrame::End { seq } => state.ended_at = Some(seq),
+    }
+
+    while let Some(payload) = state.pending.remove(&state.next_seq) {
+        state.next_seq += 1;
+        if sink.tx.unbounded_send(payload).is_err() {
+            break;
+        }
+    }
+
+    state.ended_at.map(|end| state.next_seq >= end).unwrap_or(false)
+}
+
+/// Shared send path behind both [`HoprTransport::send_session_initiation_message`] and
+/// [`StreamResponder::send_frame`]: JSON-encodes `message`, wraps it in an [`ApplicationData`] on
+/// `tag`, resolves a path to `peer`, and waits for the packet to be fully processed. A free
+/// function over explicit handles (rather than `&self`) for the same reason
+/// `send_session_initiation_message` is: callers only hold clones of these handles, not a
+/// reference to the whole transport.
+async fn send_tagged_json<T, M>(
+    process_packet_send: &OnceLock<MsgSender>,
+    path_planner: &helpers::PathPlanner<T>,
+    peer: PeerId,
+    path_options: PathOptions,
+    tag: u16,
+    message: &M,
+    packet_queue_timeout: std::time::Duration,
+) -> errors::Result<()>
+where
+    T: HoprDbAllOperations + std::fmt::Debug + Clone + Send + Sync + 'static,
+    M: Serialize,
+{
+    let bytes: Box<[u8]> = serde_json::to_vec(message)
+        .map_err(|e| HoprTransportError::Api(format!("failed to encode tagged message: {e}")))?
+        .into_boxed_slice();
+
+    let app_data = ApplicationData::new_from_owned(Some(tag), bytes)?;
+    let path = path_planner.resolve_path(peer, path_options).await?;
+
+    let sender = process_packet_send
+        .get()
+        .ok_or_else(|| HoprTransportError::Api("tagged message: message processing is not yet initialized".into()))?;
+
+    sender
+        .send_packet(app_data, path)
+        .await
+        .map_err(|e| HoprTransportError::Api(format!("tagged message failed to enqueue: {e}")))?
+        .consume_and_wait(packet_queue_timeout)
+        .await
+        .map_err(|e| HoprTransportError::Api(e.to_string()))
+}
+
+/// Result of a session-initiation handshake delivered to the initiator's pending [`new_session`]
+/// call once the responder's [`SessionInitiationMessage::SynAck`] or [`SessionInitiationMessage::Reject`] arrives.
+enum SessionInitiationOutcome {
+    Established {
+        agreed_tag: u16,
+        capabilities: Vec<SessionCapability>,
+    },
+    Rejected(String),
+    CapabilityReport {
+        supported: bool,
+        capabilities: Vec<SessionCapability>,
+    },
+}
+
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
 pub enum HoprTransportProcess {
     Heartbeat,
     Swarm,
+    HolePunch,
     ProtocolAckIn,
     ProtocolAckOut,
     ProtocolMsgIn,
     ProtocolMsgOut,
     SessionsManagement,
     BloomFilterSave,
+    NetworkExpiry,
+    SessionPeerWatch,
+    SessionVisibilitySweep,
 }
 
 #[derive(Debug, Clone)]
@@ -146,11 +451,255 @@ where
     }
 }
 
+/// AutoNAT-style confirmed reachability of one of our own candidate multiaddresses, as inferred
+/// from the votes peers return when asked to dial us back on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressReachability {
+    Unknown,
+    Public,
+    Private,
+}
+
+/// The node's inferred global NAT status, returned by [`HoprTransport::nat_status`]: [`Self::Public`]
+/// once at least one candidate address has been confirmed reachable, [`Self::Private`] if every
+/// address with a confirmed verdict turned out unreachable, or [`Self::Unknown`] while probing is
+/// still pending.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NatStatus {
+    Unknown,
+    Public,
+    Private,
+}
+
+/// How many consecutive dial-back votes for the same outcome are needed before
+/// [`NatStatusTracker`] flips an address's confirmed status, so a single flaky or dishonest prober
+/// can't bounce an address between [`AddressReachability::Public`] and [`AddressReachability::Private`].
+const NAT_STATUS_CONFIDENCE_THRESHOLD: u32 = 3;
+
+#[derive(Debug, Clone, Copy)]
+struct AddressVoteState {
+    confirmed: AddressReachability,
+    pending: AddressReachability,
+    streak: u32,
+}
+
+impl Default for AddressVoteState {
+    fn default() -> Self {
+        Self {
+            confirmed: AddressReachability::Unknown,
+            pending: AddressReachability::Unknown,
+            streak: 0,
+        }
+    }
+}
+
+/// Per-candidate-address AutoNAT reachability state backing [`HoprTransport::nat_status`] and the
+/// `Public`-only filtering in [`HoprTransport::announceable_multiaddresses`]. Fed by
+/// [`HoprTransport::report_address_reachability`], which the (swarm-level) AutoNAT prober calls
+/// with the outcome of periodically asking a small rotating set of peers to dial us back on a
+/// candidate address.
+#[derive(Debug, Default)]
+struct NatStatusTracker {
+    addresses: Mutex<HashMap<Multiaddr, AddressVoteState>>,
+}
+
+impl NatStatusTracker {
+    /// Records one dial-back vote for `addr`: `reachable` is whether the peer we asked reported a
+    /// successful connection. Only flips `addr`'s confirmed status once
+    /// [`NAT_STATUS_CONFIDENCE_THRESHOLD`] consecutive votes agree on the same outcome.
+    fn record_vote(&self, addr: Multiaddr, reachable: bool) {
+        let vote = if reachable {
+            AddressReachability::Public
+        } else {
+            AddressReachability::Private
+        };
+
+        let mut addresses = self.addresses.lock().unwrap();
+        let state = addresses.entry(addr).or_default();
+
+        if state.pending == vote {
+            state.streak += 1;
+        } else {
+            state.pending = vote;
+            state.streak = 1;
+        }
+
+        if state.streak >= NAT_STATUS_CONFIDENCE_THRESHOLD {
+            state.confirmed = vote;
+        }
+    }
+
+    fn status(&self, addr: &Multiaddr) -> AddressReachability {
+        self.addresses
+            .lock()
+            .unwrap()
+            .get(addr)
+            .map(|s| s.confirmed)
+            .unwrap_or(AddressReachability::Unknown)
+    }
+
+    fn global_status(&self) -> NatStatus {
+        let addresses = self.addresses.lock().unwrap();
+        if addresses.values().any(|s| s.confirmed == AddressReachability::Public) {
+            NatStatus::Public
+        } else if addresses.values().any(|s| s.confirmed == AddressReachability::Private) {
+            NatStatus::Private
+        } else {
+            NatStatus::Unknown
+        }
+    }
+}
+
+/// The part of [`HoprTransport`] that [`HoprTransport::install_os_signal_shutdown_handler`] needs
+/// on its signal-handling thread. Kept separate so that the handler doesn't need `HoprTransport`
+/// itself to be `Clone`, since most of its fields (the db, the path planner) are not meant to be
+/// duplicated just to wire up Ctrl-C.
+#[derive(Clone)]
+struct ShutdownHandle {
+    shutdown_tx: Arc<Mutex<Option<oneshot::Sender<()>>>>,
+}
+
+impl ShutdownHandle {
+    fn shutdown(&self) {
+        if let Some(tx) = self.shutdown_tx.lock().unwrap().take() {
+            let _ = tx.send(());
+        }
+    }
+}
+
+/// Handed to the embedder via the `incoming_response_stream_queue` passed into
+/// [`HoprTransport::run`] whenever a peer opens a response stream with
+/// [`HoprTransport::open_response_stream`]. The embedder calls [`Self::send`] once per chunk of its
+/// reply, in order, and [`Self::finish`] exactly once to mark the stream's end; sequencing and the
+/// [`ResponseStreamFrame`] wire framing are handled for it.
+#[derive(Clone)]
+pub struct StreamResponder<T>
+where
+    T: HoprDbAllOperations + std::fmt::Debug + Clone + Send + Sync + 'static,
+{
+    peer: PeerId,
+    tag: u16,
+    next_seq: Arc<AtomicU32>,
+    process_packet_send: Arc<OnceLock<MsgSender>>,
+    path_planner: helpers::PathPlanner<T>,
+    packet_queue_timeout: std::time::Duration,
+}
+
+impl<T> StreamResponder<T>
+where
+    T: HoprDbAllOperations + std::fmt::Debug + Clone + Send + Sync + 'static,
+{
+    /// Sends the next ordered chunk of the reply.
+    pub async fn send(&self, payload: Box<[u8]>) -> errors::Result<()> {
+        let seq = self.next_seq.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        self.send_frame(ResponseStreamFrame::Data { seq, payload }).await
+    }
+
+    /// Marks the end of the stream: the requester's `Stream` ends once every payload up to this
+    /// point has been delivered. Call exactly once, after the last [`Self::send`].
+    pub async fn finish(&self) -> errors::Result<()> {
+        let seq = self.next_seq.load(std::sync::atomic::Ordering::SeqCst);
+        self.send_frame(ResponseStreamFrame::End { seq }).await
+    }
+
+    async fn send_frame(&self, frame: ResponseStreamFrame) -> errors::Result<()> {
+        send_tagged_json(
+            &self.process_packet_send,
+            &self.path_planner,
+            self.peer,
+            PathOptions::IntermediatePath(vec![]),
+            self.tag,
+            &frame,
+            self.packet_queue_timeout,
+        )
+        .await
+    }
+}
+
+/// Runtime-adjustable counterpart to the session-related constants in [`constants`], so operators
+/// with long-lived or latency-sensitive tunnels can tune idle lifetime and queue timeouts without
+/// recompiling, and so tests can inject short timeouts deterministically.
+///
+/// Construct via [`Self::new`] (validated) rather than building the struct literal directly; the
+/// [`Default`] impl reproduces the behavior of the compile-time constants it replaces.
+#[derive(Debug, Clone, Copy)]
+pub struct SessionConfig {
+    /// Used in place of [`constants::PACKET_QUEUE_TIMEOUT_MILLISECONDS`] for session-initiation
+    /// messages, session traffic and response-stream frames; other messages sent through
+    /// [`HoprTransport`] keep waiting on that constant.
+    pub packet_queue_timeout: std::time::Duration,
+    /// Replaces [`constants::SESSION_INITIATION_TIMEOUT`].
+    pub session_initiation_timeout: std::time::Duration,
+    /// Replaces [`constants::SESSION_LIFETIME`] as the idle threshold after which
+    /// [`SessionVisibility::Suspended`] is applied.
+    pub session_lifetime: std::time::Duration,
+    /// Upper bound (exclusive) of the tag range handed out to new sessions. Must not exceed the
+    /// protocol's own wire-level [`constants::RESERVED_SESSION_TAG_UPPER_LIMIT`].
+    pub reserved_session_tag_upper_limit: u16,
+    /// Upper bound (exclusive) of the tag range reserved for subprotocol use, below which no
+    /// session tag is ever allocated. Must not be below the protocol's own wire-level
+    /// [`constants::RESERVED_SUBPROTOCOL_TAG_UPPER_LIMIT`].
+    pub reserved_subprotocol_tag_upper_limit: u16,
+}
+
+impl Default for SessionConfig {
+    fn default() -> Self {
+        Self {
+            packet_queue_timeout: constants::PACKET_QUEUE_TIMEOUT_MILLISECONDS,
+            session_initiation_timeout: constants::SESSION_INITIATION_TIMEOUT,
+            session_lifetime: constants::SESSION_LIFETIME,
+            reserved_session_tag_upper_limit: constants::RESERVED_SESSION_TAG_UPPER_LIMIT,
+            reserved_subprotocol_tag_upper_limit: constants::RESERVED_SUBPROTOCOL_TAG_UPPER_LIMIT,
+        }
+    }
+}
+
+impl SessionConfig {
+    /// Builds a [`SessionConfig`], rejecting combinations that would violate invariants the
+    /// Start-protocol wire dispatch relies on.
+    pub fn new(
+        packet_queue_timeout: std::time::Duration,
+        session_initiation_timeout: std::time::Duration,
+        session_lifetime: std::time::Duration,
+        reserved_session_tag_upper_limit: u16,
+        reserved_subprotocol_tag_upper_limit: u16,
+    ) -> errors::Result<Self> {
+        if reserved_subprotocol_tag_upper_limit >= reserved_session_tag_upper_limit {
+            return Err(errors::HoprTransportError::Api(
+                "reserved_subprotocol_tag_upper_limit must stay below reserved_session_tag_upper_limit".into(),
+            ));
+        }
+
+        if reserved_session_tag_upper_limit > constants::RESERVED_SESSION_TAG_UPPER_LIMIT
+            || reserved_subprotocol_tag_upper_limit < constants::RESERVED_SUBPROTOCOL_TAG_UPPER_LIMIT
+        {
+            return Err(errors::HoprTransportError::Api(
+                "session tag range must stay within the protocol's reserved tag boundaries".into(),
+            ));
+        }
+
+        if session_lifetime <= session_initiation_timeout {
+            return Err(errors::HoprTransportError::Api(
+                "session_lifetime must exceed session_initiation_timeout".into(),
+            ));
+        }
+
+        Ok(Self {
+            packet_queue_timeout,
+            session_initiation_timeout,
+            session_lifetime,
+            reserved_session_tag_upper_limit,
+            reserved_subprotocol_tag_upper_limit,
+        })
+    }
+}
+
 pub struct HoprTransportConfig {
     pub transport: config::TransportConfig,
     pub network: core_network::config::NetworkConfig,
     pub protocol: hopr_transport_protocol::config::ProtocolConfig,
     pub heartbeat: core_network::heartbeat::HeartbeatConfig,
+    pub session: SessionConfig,
 }
 
 /// Interface into the physical transport mechanism allowing all off-chain HOPR related tasks on
@@ -171,6 +720,23 @@ where
     process_ticket_aggregate:
         Arc<OnceLock<TicketAggregationActions<TicketAggregationResponseType, TicketAggregationRequestType>>>,
     sessions: moka::future::Cache<SessionId, UnboundedSender<Box<[u8]>>>,
+    response_streams: moka::future::Cache<SessionId, ResponseStreamSink>,
+    pending_initiations: Arc<Mutex<HashMap<StartChallenge, oneshot::Sender<SessionInitiationOutcome>>>>,
+    next_initiation_challenge: Arc<AtomicU64>,
+    session_events: Arc<Mutex<Vec<UnboundedSender<SessionLifecycleEvent>>>>,
+    session_peers: Arc<Mutex<HashMap<SessionId, PeerId>>>,
+    session_visibility: Arc<Mutex<HashMap<SessionId, (std::time::Instant, SessionVisibility)>>>,
+    session_visibility_thresholds: SessionVisibilityThresholds,
+    session_cfg: SessionConfig,
+    shutdown_tx: Arc<Mutex<Option<oneshot::Sender<()>>>>,
+    shutdown_rx: futures::future::Shared<oneshot::Receiver<()>>,
+    nat_status: Arc<NatStatusTracker>,
+    /// Runtime toggle for mDNS local-peer discovery, seeded from
+    /// [`config::TransportConfig::mdns_enabled`] (off by default) but switchable at runtime via
+    /// [`HoprTransport::set_mdns_enabled`] without requiring a restart.
+    mdns_enabled: Arc<AtomicBool>,
+    dnsaddr_resolver: Arc<dyn dnsaddr::TxtLookup>,
+    dnsaddr_cache: Arc<dnsaddr::DnsaddrCache>,
 }
 
 impl<T> HoprTransport<T>
@@ -186,6 +752,21 @@ where
         my_multiaddresses: Vec<Multiaddr>,
     ) -> Self {
         let identity: libp2p::identity::Keypair = (me).into();
+        let (shutdown_tx, shutdown_rx) = oneshot::channel();
+        let mdns_enabled = cfg.transport.mdns_enabled;
+        let session_cfg = cfg.session;
+
+        let session_events: Arc<Mutex<Vec<UnboundedSender<SessionLifecycleEvent>>>> = Arc::new(Mutex::new(Vec::new()));
+        let session_peers: Arc<Mutex<HashMap<SessionId, PeerId>>> = Arc::new(Mutex::new(HashMap::new()));
+        let session_visibility: Arc<Mutex<HashMap<SessionId, (std::time::Instant, SessionVisibility)>>> =
+            Arc::new(Mutex::new(HashMap::new()));
+        let session_visibility_thresholds = SessionVisibilityThresholds {
+            suspend_after: session_cfg.session_lifetime,
+            ..SessionVisibilityThresholds::default()
+        };
+        let session_events_for_evict = session_events.clone();
+        let session_peers_for_evict = session_peers.clone();
+        let session_visibility_for_evict = session_visibility.clone();
 
         Self {
             me: identity.public().to_peer_id(),
@@ -204,9 +785,45 @@ where
             process_packet_send: Arc::new(OnceLock::new()),
             process_ticket_aggregate: Arc::new(OnceLock::new()),
             sessions: moka::future::Cache::builder()
+                .max_capacity(u16::MAX as u64)
+                .time_to_idle(constants::SESSION_HARD_EXPIRE_AFTER)
+                .eviction_listener(move |key, _value, cause| {
+                    let id: SessionId = (*key).clone();
+                    session_peers_for_evict.lock().unwrap().remove(&id);
+                    session_visibility_for_evict.lock().unwrap().remove(&id);
+
+                    match cause {
+                        moka::notification::RemovalCause::Expired => {
+                            emit_lifecycle_event(&session_events_for_evict, SessionLifecycleEvent::Idle(id));
+                            emit_lifecycle_event(&session_events_for_evict, SessionLifecycleEvent::Expired(id));
+                        }
+                        other => emit_lifecycle_event(
+                            &session_events_for_evict,
+                            SessionLifecycleEvent::Ended {
+                                id,
+                                reason: format!("{other:?}"),
+                            },
+                        ),
+                    }
+                })
+                .build(),
+            response_streams: moka::future::Cache::builder()
                 .max_capacity(u16::MAX as u64)
                 .time_to_idle(std::time::Duration::from_secs(5 * 60))
                 .build(),
+            pending_initiations: Arc::new(Mutex::new(HashMap::new())),
+            next_initiation_challenge: Arc::new(AtomicU64::new(constants::MIN_CHALLENGE)),
+            session_events,
+            session_peers,
+            session_visibility,
+            session_visibility_thresholds,
+            session_cfg,
+            shutdown_tx: Arc::new(Mutex::new(Some(shutdown_tx))),
+            shutdown_rx: shutdown_rx.shared(),
+            nat_status: Arc::new(NatStatusTracker::default()),
+            mdns_enabled: Arc::new(AtomicBool::new(mdns_enabled)),
+            dnsaddr_resolver: Arc::new(dnsaddr::NativeTxtLookup::default()),
+            dnsaddr_cache: Arc::new(dnsaddr::DnsaddrCache::new()),
         }
     }
 
@@ -221,9 +838,29 @@ where
     /// Execute all processes of the [`crate::HoprTransport`] object.
     ///
     /// This method will spawn the [`crate::HoprTransportProcess::Heartbeat`], [`crate::HoprTransportProcess::BloomFilterSave`],
-    /// [`crate::HoprTransportProcess::Swarm`] and [`crate::HoprTransportProcess::SessionsRouter`] processes and return
+    /// [`crate::HoprTransportProcess::Swarm`], [`crate::HoprTransportProcess::HolePunch`] and
+    /// [`crate::HoprTransportProcess::SessionsManagement`] processes and return
     /// join handles to the calling function. These processes are not started immediately, but are
     /// waiting for a trigger from this piece of code.
+    ///
+    /// The configured [`config::TransportConfig::network_id`] is handed to [`HoprSwarm`] so that it
+    /// can be advertised and checked over the libp2p identify exchange: a session stays
+    /// "unidentified" until the remote's advertised ID is observed to match, the msg-ack and
+    /// ticket-aggregation sub-protocols are refused for a mismatch, and any multiaddr the swarm
+    /// would otherwise have surfaced as a [`PeerDiscovery`] observation for that peer is dropped
+    /// instead. [`Self::on_peer_identity_mismatch`] is the hook the identify handler calls to make
+    /// sure a peer that fails the check is actively removed from [`Network`] rather than left to
+    /// linger as a recorded observation.
+    ///
+    /// Every spawned process carries a clone of the cancellation signal triggered by
+    /// [`Self::shutdown`], so that a shutdown drains in-flight work (outbound queues, a final
+    /// bloom-filter persist, open sessions) and returns normally instead of being aborted. Call
+    /// [`Self::join_shutdown`] on the returned handles to wait for that drain to finish.
+    ///
+    /// `incoming_response_stream_queue` is the [`StreamResponder`] counterpart of
+    /// `incoming_session_queue`: it receives one handle per peer-initiated
+    /// [`Self::open_response_stream`], for the embedder to push an ordered reply onto via
+    /// [`StreamResponder::send`] and [`StreamResponder::finish`].
     #[allow(clippy::too_many_arguments)]
     pub async fn run(
         &self,
@@ -236,6 +873,7 @@ where
         on_acknowledged_ticket: UnboundedSender<AcknowledgedTicket>,
         transport_updates: UnboundedReceiver<PeerDiscovery>,
         incoming_session_queue: UnboundedSender<Session>,
+        incoming_response_stream_queue: UnboundedSender<StreamResponder<T>>,
     ) -> HashMap<HoprTransportProcess, JoinHandle<()>> {
         let mut processes: HashMap<HoprTransportProcess, JoinHandle<()>> = HashMap::new();
 
@@ -271,6 +909,7 @@ where
         let ticket_agg_proc = TicketAggregationInteraction::new(self.db.clone(), me_onchain);
         let tkt_agg_writer = ticket_agg_proc.writer();
 
+        // on shutdown the swarm drains its outbound queues instead of dropping in-flight packets
         let transport_layer = HoprSwarm::new(
             me.into(),
             network_events_rx,
@@ -279,6 +918,8 @@ where
             ticket_agg_proc,
             self.my_multiaddresses.clone(),
             self.cfg.protocol,
+            self.cfg.transport.network_id.clone(),
+            self.shutdown_rx.clone(),
         )
         .await;
 
@@ -313,23 +954,43 @@ where
         let (msg_to_send_tx, msg_to_send_rx) = futures::channel::mpsc::unbounded::<(PeerId, Box<[u8]>)>();
         let (msg_received_tx, msg_received_rx) = futures::channel::mpsc::unbounded::<(PeerId, Box<[u8]>)>();
 
-        let transport_layer = transport_layer.with_processors(
-            ack_to_send_rx,
-            ack_received_tx,
-            msg_to_send_rx,
-            msg_received_tx,
-            tkt_agg_writer,
-        );
+        // once a relayed connection to a peer exists, the swarm exchanges observed external
+        // addresses with it (reported over the network-event channel as
+        // `NetworkTriggeredEvent::ObservedAddress`) and attempts a synchronized simultaneous-open
+        // dial to upgrade to a direct connection, with a random nonce deciding which side acts as
+        // the multistream initiator. `split_hole_punch` hands back that loop as its own future so
+        // it can run under its own `HoprTransportProcess::HolePunch` join handle instead of
+        // blocking the main swarm loop; a dial that does not complete within
+        // `constants::HOLE_PUNCH_TIMEOUT` is abandoned and the relayed path keeps serving traffic.
+        let (transport_layer, hole_punch_loop) = transport_layer
+            .with_processors(
+                ack_to_send_rx,
+                ack_received_tx,
+                msg_to_send_rx,
+                msg_received_tx,
+                tkt_agg_writer,
+            )
+            .split_hole_punch(constants::HOLE_PUNCH_TIMEOUT);
 
         processes.insert(
             HoprTransportProcess::Swarm,
             spawn(transport_layer.run(version, on_acknowledged_ticket.clone())),
         );
 
+        processes.insert(HoprTransportProcess::HolePunch, spawn(hole_punch_loop));
+
         // initiate the msg-ack protocol stack over the wire transport
-        let packet_cfg = PacketInteractionConfig::new(me, me_onchain);
+        //
+        // the per-packet onion/SPHINX and ticket crypto driving `ProtocolMsgIn`/`ProtocolMsgOut`
+        // runs on its own bounded CPU thread pool rather than inline on the async executor, so a
+        // burst of traffic on one session cannot starve heartbeat or session management; the pool
+        // is sized from `crypto_thread_pool_size` (defaulting to `num_cpus::get()`, see
+        // `ProtocolConfig::default`) and the crypto workers preserve per-channel ordering so the
+        // bloom-filter replay check never sees an acknowledgement reordered ahead of its packet.
+        let packet_cfg = PacketInteractionConfig::new(me, me_onchain, self.cfg.protocol.crypto_thread_pool_size);
 
         let (tx_from_protocol, rx_from_protocol) = futures::channel::mpsc::unbounded::<ApplicationData>();
+        // on shutdown, `BloomFilterSave` performs one last persist to `tbf_path` before returning
         for (k, v) in hopr_transport_protocol::run_msg_ack_protocol(
             packet_cfg,
             self.db.clone(),
@@ -339,6 +1000,7 @@ where
             (ack_to_send_tx, ack_received_rx),
             (msg_to_send_tx, msg_received_rx),
             (tx_from_protocol, external_msg_rx),
+            self.shutdown_rx.clone(),
         )
         .await
         .into_iter()
@@ -357,47 +1019,340 @@ where
 
         // initiate session handling over the msg-ack protocol stack
         let sessions = self.sessions.clone();
+        let sessions_for_drain = self.sessions.clone();
+        let response_streams = self.response_streams.clone();
+        let response_streams_for_drain = self.response_streams.clone();
+        let shutdown_rx = self.shutdown_rx.clone();
         let me = self.me;
         let message_sender = Arc::new(helpers::MessageSender::new(
             self.process_packet_send.clone(),
             self.path_planner.clone(),
         ));
+        let pending_initiations = self.pending_initiations.clone();
+        let process_packet_send = self.process_packet_send.clone();
+        let path_planner = self.path_planner.clone();
+        let session_events = self.session_events.clone();
+        let session_peers = self.session_peers.clone();
+        let session_visibility = self.session_visibility.clone();
+        let packet_queue_timeout = self.session_cfg.packet_queue_timeout;
+        let reserved_session_tag_upper_limit = self.session_cfg.reserved_session_tag_upper_limit;
+        let reserved_subprotocol_tag_upper_limit = self.session_cfg.reserved_subprotocol_tag_upper_limit;
 
         processes.insert(
             HoprTransportProcess::SessionsManagement,
             spawn(async move {
-                let _the_process_should_not_end = StreamExt::filter_map(rx_from_protocol, move |data| {
+                let processing = StreamExt::filter_map(rx_from_protocol, move |data| {
                     let sessions = sessions.clone();
+                    let response_streams = response_streams.clone();
+                    let session_events = session_events.clone();
+                    let session_peers = session_peers.clone();
+                    let session_visibility = session_visibility.clone();
+                    let packet_queue_timeout = packet_queue_timeout;
+                    let reserved_session_tag_upper_limit = reserved_session_tag_upper_limit;
+                    let reserved_subprotocol_tag_upper_limit = reserved_subprotocol_tag_upper_limit;
                     let me = me;
                     let message_sender = message_sender.clone();
                     let incoming_session_queue = incoming_session_queue.clone();
+                    let incoming_response_stream_queue = incoming_response_stream_queue.clone();
+                    let pending_initiations = pending_initiations.clone();
+                    let process_packet_send = process_packet_send.clone();
+                    let path_planner = path_planner.clone();
 
                     async move {
+                        #[cfg(all(feature = "prometheus", not(test)))]
+                        METRIC_MESSAGES_IN.increment();
+
                         if let Some(app_tag) = data.application_tag {
                             const SPECIAL_TAG_HIGHEST_VALUE: u16 = RESERVED_SUBPROTOCOL_TAG_UPPER_LIMIT - 1;
                             const SESSION_TAG_HIGHEST_VALUE: u16 = RESERVED_SESSION_TAG_UPPER_LIMIT - 1;
                             match app_tag {
+                                constants::SESSION_INITIATION_TAG => {
+                                    if let Ok((peer, payload)) =
+                                        hopr_transport_session::types::unwrap_offchain_key(data.plain_text.clone())
+                                    {
+                                        if let Ok(message) =
+                                            serde_json::from_slice::<SessionInitiationMessage>(&payload)
+                                        {
+                                            match message {
+                                                SessionInitiationMessage::Syn {
+                                                    challenge,
+                                                    proposed_tag,
+                                                    capabilities,
+                                                } => {
+                                                    let reply = if sessions
+                                                        .contains_key(&SessionId::new(proposed_tag, peer))
+                                                    {
+                                                        let mut counter_proposal = None;
+                                                        let mut retries = 0u32;
+                                                        for _ in 0..10 {
+                                                            let candidate = hopr_crypto_random::random_integer(
+                                                                reserved_subprotocol_tag_upper_limit as u64,
+                                                                Some(reserved_session_tag_upper_limit as u64),
+                                                            )
+                                                                as u16;
+                                                            if !sessions.contains_key(&SessionId::new(candidate, peer))
+                                                            {
+                                                                counter_proposal = Some(candidate);
+                                                                break;
+                                                            }
+                                                            retries += 1;
+                                                        }
+
+                                                        #[cfg(all(feature = "prometheus", not(test)))]
+                                                        METRIC_SESSION_TAG_RETRIES.observe(retries as f64);
+
+                                                        match counter_proposal {
+                                                            Some(agreed_tag) => Ok((agreed_tag, capabilities)),
+                                                            None => Err(
+                                                                "no free session tag available".to_string(),
+                                                            ),
+                                                        }
+                                                    } else {
+                                                        Ok((proposed_tag, capabilities))
+                                                    };
+
+                                                    let reply_message = match reply {
+                                                        Ok((agreed_tag, capabilities)) => {
+                                                            let session_id = SessionId::new(agreed_tag, peer);
+                                                            let (tx, rx) =
+                                                                futures::channel::mpsc::unbounded::<Box<[u8]>>();
+
+                                                            if incoming_session_queue
+                                                                .unbounded_send(Session::new(
+                                                                    session_id,
+                                                                    me,
+                                                                    PathOptions::IntermediatePath(vec![]),
+                                                                    capabilities.clone(),
+                                                                    message_sender.clone(),
+                                                                    rx,
+                                                                ))
+                                                                .is_ok()
+                                                            {
+                                                                sessions.insert(session_id, tx).await;
+                                                                session_peers.lock().unwrap().insert(session_id, peer);
+                                                                session_visibility.lock().unwrap().insert(
+                                                                    session_id,
+                                                                    (std::time::Instant::now(), SessionVisibility::Visible),
+                                                                );
+                                                                emit_lifecycle_event(
+                                                                    &session_events,
+                                                                    SessionLifecycleEvent::Established {
+                                                                        id: session_id,
+                                                                        tag: agreed_tag,
+                                                                        peer,
+                                                                    },
+                                                                );
+
+                                                                #[cfg(all(feature = "prometheus", not(test)))]
+                                                                {
+                                                                    METRIC_SESSIONS_OPENED.increment();
+                                                                    METRIC_ACTIVE_SESSIONS.set(sessions.entry_count() as f64);
+                                                                }
+                                                            } else {
+                                                                warn!("Failed to send session to incoming session queue");
+                                                            }
+
+                                                            SessionInitiationMessage::SynAck {
+                                                                challenge,
+                                                                agreed_tag,
+                                                                capabilities,
+                                                            }
+                                                        }
+                                                        Err(reason) => SessionInitiationMessage::Reject {
+                                                            challenge,
+                                                            reason,
+                                                        },
+                                                    };
+
+                                                    if let Err(e) = HoprTransport::<T>::send_session_initiation_message(
+                                                        &process_packet_send,
+                                                        &path_planner,
+                                                        peer,
+                                                        PathOptions::IntermediatePath(vec![]),
+                                                        reply_message,
+                                                        packet_queue_timeout,
+                                                    )
+                                                    .await
+                                                    {
+                                                        error!("Failed to reply to session initiation: {e}");
+                                                    }
+                                                }
+                                                SessionInitiationMessage::SynStream { challenge, proposed_tag } => {
+                                                    let reply = if sessions
+                                                        .contains_key(&SessionId::new(proposed_tag, peer))
+                                                        || response_streams
+                                                            .contains_key(&SessionId::new(proposed_tag, peer))
+                                                    {
+                                                        let mut counter_proposal = None;
+                                                        for _ in 0..10 {
+                                                            let candidate = hopr_crypto_random::random_integer(
+                                                                reserved_subprotocol_tag_upper_limit as u64,
+                                                                Some(reserved_session_tag_upper_limit as u64),
+                                                            )
+                                                                as u16;
+                                                            let candidate_id = SessionId::new(candidate, peer);
+                                                            if !sessions.contains_key(&candidate_id)
+                                                                && !response_streams.contains_key(&candidate_id)
+                                                            {
+                                                                counter_proposal = Some(candidate);
+                                                                break;
+                                                            }
+                                                        }
+
+                                                        counter_proposal
+                                                            .ok_or_else(|| "no free session tag available".to_string())
+                                                    } else {
+                                                        Ok(proposed_tag)
+                                                    };
+
+                                                    let reply_message = match reply {
+                                                        Ok(agreed_tag) => {
+                                                            let responder = StreamResponder {
+                                                                peer,
+                                                                tag: agreed_tag,
+                                                                next_seq: Arc::new(AtomicU32::new(0)),
+                                                                process_packet_send: process_packet_send.clone(),
+                                                                path_planner: path_planner.clone(),
+                                                                packet_queue_timeout,
+                                                            };
+
+                                                            if incoming_response_stream_queue
+                                                                .unbounded_send(responder)
+                                                                .is_err()
+                                                            {
+                                                                warn!(
+                                                                    "Failed to send response stream to incoming response stream queue"
+                                                                );
+                                                            }
+
+                                                            SessionInitiationMessage::SynAck {
+                                                                challenge,
+                                                                agreed_tag,
+                                                                capabilities: vec![],
+                                                            }
+                                                        }
+                                                        Err(reason) => SessionInitiationMessage::Reject {
+                                                            challenge,
+                                                            reason,
+                                                        },
+                                                    };
+
+                                                    if let Err(e) = HoprTransport::<T>::send_session_initiation_message(
+                                                        &process_packet_send,
+                                                        &path_planner,
+                                                        peer,
+                                                        PathOptions::IntermediatePath(vec![]),
+                                                        reply_message,
+                                                        packet_queue_timeout,
+                                                    )
+                                                    .await
+                                                    {
+                                                        error!("Failed to reply to response stream initiation: {e}");
+                                                    }
+                                                }
+                                                SessionInitiationMessage::SynAck {
+                                                    challenge,
+                                                    agreed_tag,
+                                                    capabilities,
+                                                } => {
+                                                    if let Some(tx) =
+                                                        pending_initiations.lock().unwrap().remove(&challenge)
+                                                    {
+                                                        let _ = tx.send(SessionInitiationOutcome::Established {
+                                                            agreed_tag,
+                                                            capabilities,
+                                                        });
+                                                    }
+                                                }
+                                                SessionInitiationMessage::Reject { challenge, reason } => {
+                                                    if let Some(tx) =
+                                                        pending_initiations.lock().unwrap().remove(&challenge)
+                                                    {
+                                                        let _ = tx.send(SessionInitiationOutcome::Rejected(reason));
+                                                    }
+                                                }
+                                                SessionInitiationMessage::CapabilityQuery { challenge, mode } => {
+                                                    let supported = mode.tag_class_limit
+                                                        >= reserved_subprotocol_tag_upper_limit
+                                                        && mode.tag_class_limit < reserved_session_tag_upper_limit
+                                                        && mode.max_segment_size <= SESSION_USABLE_MTU_SIZE;
+
+                                                    let reply_message = SessionInitiationMessage::CapabilityReply {
+                                                        challenge,
+                                                        supported,
+                                                        capabilities: vec![],
+                                                    };
+
+                                                    if let Err(e) = HoprTransport::<T>::send_session_initiation_message(
+                                                        &process_packet_send,
+                                                        &path_planner,
+                                                        peer,
+                                                        PathOptions::IntermediatePath(vec![]),
+                                                        reply_message,
+                                                        packet_queue_timeout,
+                                                    )
+                                                    .await
+                                                    {
+                                                        error!("Failed to reply to capability query: {e}");
+                                                    }
+                                                }
+                                                SessionInitiationMessage::CapabilityReply {
+                                                    challenge,
+                                                    supported,
+                                                    capabilities,
+                                                } => {
+                                                    if let Some(tx) =
+                                                        pending_initiations.lock().unwrap().remove(&challenge)
+                                                    {
+                                                        let _ = tx.send(SessionInitiationOutcome::CapabilityReport {
+                                                            supported,
+                                                            capabilities,
+                                                        });
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                    None
+                                }
                                 0..=SPECIAL_TAG_HIGHEST_VALUE => None,
                                 RESERVED_SUBPROTOCOL_TAG_UPPER_LIMIT..=SESSION_TAG_HIGHEST_VALUE => {
                                     if let Ok((peer, data)) =
                                         hopr_transport_session::types::unwrap_offchain_key(data.plain_text.clone())
                                     {
-                                        if let Some(sender) = sessions.get(&SessionId::new(app_tag, peer)).await {
+                                        let session_id = SessionId::new(app_tag, peer);
+
+                                        if let Some(sender) = sessions.get(&session_id).await {
                                             trace!(
                                                 app_tag,
                                                 peer_id = tracing::field::debug(peer),
                                                 "Received data for a registered session"
                                             );
+                                            // any traffic resumes a `Suspended` session without a fresh
+                                            // Start handshake, same as a `Visible` one just has its idle
+                                            // clock reset
+                                            session_visibility.lock().unwrap().insert(
+                                                session_id,
+                                                (std::time::Instant::now(), SessionVisibility::Visible),
+                                            );
                                             if let Err(e) = sender.unbounded_send(data) {
                                                 error!("Failed to send data to session: {e}");
                                             }
+                                        } else if let Some(sink) = response_streams.get(&session_id).await {
+                                            match serde_json::from_slice::<ResponseStreamFrame>(&data) {
+                                                Ok(frame) => {
+                                                    if ingest_response_stream_frame(&sink, frame) {
+                                                        response_streams.invalidate(&session_id).await;
+                                                    }
+                                                }
+                                                Err(e) => error!("Failed to decode response stream frame: {e}"),
+                                            }
                                         } else {
                                             info!(
                                                 app_tag,
                                                 peer_id = tracing::field::debug(peer),
                                                 "Detected a new incoming session"
                                             );
-                                            let session_id = SessionId::new(app_tag, peer);
 
                                             let (tx, rx) = futures::channel::mpsc::unbounded::<Box<[u8]>>();
 
@@ -418,6 +1373,11 @@ where
                                                 }
 
                                                 sessions.insert(session_id, tx).await;
+                                                session_peers.lock().unwrap().insert(session_id, peer);
+                                                session_visibility.lock().unwrap().insert(
+                                                    session_id,
+                                                    (std::time::Instant::now(), SessionVisibility::Visible),
+                                                );
                                             } else {
                                                 warn!("Failed to send session to incoming session queue");
                                             }
@@ -434,7 +1394,21 @@ where
                 })
                 .map(Ok)
                 .forward(on_transport_output)
-                .await;
+                .fuse();
+
+                pin_mut!(processing);
+                let shutdown = shutdown_rx.fuse();
+                pin_mut!(shutdown);
+
+                if let Either::Right(_) = select(processing, shutdown).await {
+                    debug!("SessionsManagement received the shutdown signal, flushing open sessions");
+                }
+
+                // either the upstream channel closed or shutdown was triggered: either way, stop
+                // routing data into half-open sessions and drop their senders so the other end of
+                // each session observes a clean close instead of hanging
+                sessions_for_drain.invalidate_all();
+                response_streams_for_drain.invalidate_all();
             }),
         );
 
@@ -444,9 +1418,178 @@ where
             spawn(async move { heartbeat.heartbeat_loop().await }),
         );
 
+        // background sweeper pruning peers gone quiet for longer than `PEER_EXPIRY_TTL`,
+        // modeled on the "ExpirePeer" pattern: each stale peer is announced via
+        // `NetworkEvent::PeerExpired` (see `HoprTransport::subscribe_network_events`) before it is
+        // removed, so it never just silently vanishes from `network_connected_peers()`
+        let network_for_sweep = network.clone();
+        let shutdown_rx_for_sweep = self.shutdown_rx.clone();
+
+        processes.insert(
+            HoprTransportProcess::NetworkExpiry,
+            spawn(async move {
+                loop {
+                    let tick = sleep(constants::PEER_EXPIRY_SWEEP_INTERVAL).fuse();
+                    let shutdown = shutdown_rx_for_sweep.clone().fuse();
+                    pin_mut!(tick);
+                    pin_mut!(shutdown);
+
+                    if let Either::Right(_) = select(tick, shutdown).await {
+                        debug!("NetworkExpiry received the shutdown signal");
+                        break;
+                    }
+
+                    let expiry_threshold = current_time()
+                        .checked_sub(constants::PEER_EXPIRY_TTL)
+                        .unwrap_or_else(current_time);
+
+                    match network_for_sweep.sweep_expired_peers(expiry_threshold).await {
+                        Ok(expired) if !expired.is_empty() => {
+                            debug!(count = expired.len(), "Pruned expired peers from the network store")
+                        }
+                        Ok(_) => {}
+                        Err(e) => error!("Failed to sweep expired peers: {e}"),
+                    }
+                }
+            }),
+        );
+
+        // forwards `NetworkEvent::MultiaddrsUpdated` into `SessionLifecycleEvent::PeerDeviceChanged`
+        // for every session currently open with that peer, so a session consumer learns its remote
+        // end appears to have moved without having to separately subscribe to network topology
+        let session_events_for_peer_watch = self.session_events.clone();
+        let session_peers_for_peer_watch = self.session_peers.clone();
+        let mut network_events_for_peer_watch = network.subscribe_events();
+        let shutdown_rx_for_peer_watch = self.shutdown_rx.clone();
+
+        processes.insert(
+            HoprTransportProcess::SessionPeerWatch,
+            spawn(async move {
+                loop {
+                    let next_event = network_events_for_peer_watch.next().fuse();
+                    let shutdown = shutdown_rx_for_peer_watch.clone().fuse();
+                    pin_mut!(next_event, shutdown);
+
+                    match select(next_event, shutdown).await {
+                        Either::Left((Some(NetworkEvent::MultiaddrsUpdated(peer, _)), _)) => {
+                            let affected: Vec<SessionId> = session_peers_for_peer_watch
+                                .lock()
+                                .unwrap()
+                                .iter()
+                                .filter(|(_, p)| **p == peer)
+                                .map(|(id, _)| id.clone())
+                                .collect();
+
+                            for id in affected {
+                                emit_lifecycle_event(
+                                    &session_events_for_peer_watch,
+                                    SessionLifecycleEvent::PeerDeviceChanged(id),
+                                );
+                            }
+                        }
+                        Either::Left((Some(_), _)) => {}
+                        Either::Left((None, _)) => break,
+                        Either::Right(_) => {
+                            debug!("SessionPeerWatch received the shutdown signal");
+                            break;
+                        }
+                    }
+                }
+            }),
+        );
+
+        // periodically checks every open session's idle time against `session_visibility_thresholds`,
+        // flipping a neglected session from `Visible` to `Suspended` and emitting `Idle` for it; the
+        // hard cutoff itself is left to the `sessions` cache's own idle eviction (see its
+        // `eviction_listener`), which already fires `Expired` once `hard_expire_after` elapses
+        let session_events_for_visibility = self.session_events.clone();
+        let session_visibility_for_sweep = self.session_visibility.clone();
+        let suspend_after = self.session_visibility_thresholds.suspend_after;
+        let shutdown_rx_for_visibility = self.shutdown_rx.clone();
+
+        processes.insert(
+            HoprTransportProcess::SessionVisibilitySweep,
+            spawn(async move {
+                loop {
+                    let tick = sleep(constants::SESSION_VISIBILITY_SWEEP_INTERVAL).fuse();
+                    let shutdown = shutdown_rx_for_visibility.clone().fuse();
+                    pin_mut!(tick, shutdown);
+
+                    if let Either::Right(_) = select(tick, shutdown).await {
+                        debug!("SessionVisibilitySweep received the shutdown signal");
+                        break;
+                    }
+
+                    let now = std::time::Instant::now();
+                    let mut newly_suspended = Vec::new();
+
+                    {
+                        let mut visibility = session_visibility_for_sweep.lock().unwrap();
+                        for (id, (last_active, state)) in visibility.iter_mut() {
+                            if *state == SessionVisibility::Visible && now.duration_since(*last_active) >= suspend_after
+                            {
+                                *state = SessionVisibility::Suspended;
+                                newly_suspended.push(id.clone());
+                            }
+                        }
+                    }
+
+                    for id in newly_suspended {
+                        emit_lifecycle_event(&session_events_for_visibility, SessionLifecycleEvent::Idle(id));
+                    }
+                }
+            }),
+        );
+
         processes
     }
 
+    /// Triggers the cancellation signal threaded into every process spawned by [`Self::run`], so
+    /// the swarm drains its outbound queues, `BloomFilterSave` performs one last persist, and the
+    /// `sessions` cache is flushed instead of each task being aborted mid-flight. Idempotent: a
+    /// second call is a no-op, since the underlying one-shot sender can only be taken once.
+    pub fn shutdown(&self) {
+        if let Some(tx) = self.shutdown_tx.lock().unwrap().take() {
+            let _ = tx.send(());
+        }
+    }
+
+    /// Awaits every join handle returned by [`Self::run`], so the caller can find out once all
+    /// processes have actually finished draining after [`Self::shutdown`] was triggered. A free
+    /// function over the owned handles (rather than `&self`) since by the time shutdown is being
+    /// awaited, `run`'s caller is the one holding them, not `HoprTransport` itself.
+    pub async fn join_shutdown(processes: HashMap<HoprTransportProcess, JoinHandle<()>>) {
+        futures::future::join_all(processes.into_values()).await;
+    }
+
+    /// Convenience helper spawning a background OS thread that calls [`Self::shutdown`] on the
+    /// first Ctrl-C or SIGTERM received by the process. A plain thread rather than an async task,
+    /// since `signal_hook`'s portable iterator blocks until a signal arrives. Optional: embedders
+    /// that already have their own signal handling wired up (e.g. the `hoprd` daemon) can call
+    /// [`Self::shutdown`] directly instead and skip this.
+    pub fn install_os_signal_shutdown_handler(&self) -> std::thread::JoinHandle<()> {
+        let transport = self.clone_handle_for_shutdown();
+        std::thread::spawn(move || {
+            let mut signals =
+                signal_hook::iterator::Signals::new([signal_hook::consts::SIGINT, signal_hook::consts::SIGTERM])
+                    .expect("failed to register the shutdown signal handler");
+
+            if signals.forever().next().is_some() {
+                info!("Received an OS shutdown signal, stopping HoprTransport processes");
+                transport.shutdown();
+            }
+        })
+    }
+
+    /// The pieces of `self` the signal-handler task in [`Self::install_os_signal_shutdown_handler`]
+    /// actually needs in order to call [`Self::shutdown`], without requiring `HoprTransport` itself
+    /// to be `Clone`.
+    fn clone_handle_for_shutdown(&self) -> ShutdownHandle {
+        ShutdownHandle {
+            shutdown_tx: self.shutdown_tx.clone(),
+        }
+    }
+
     pub fn ticket_aggregator(&self) -> Arc<dyn TicketAggregatorTrait + Send + Sync + 'static> {
         Arc::new(TicketAggregatorProxy::new(
             self.db.clone(),
@@ -477,7 +1620,7 @@ where
 
         pin_mut!(timeout, ping);
 
-        if let Err(e) = self.network.add(peer, PeerOrigin::ManualPing, vec![]).await {
+        if let Err(e) = self.network.add(peer, PeerOrigin::ManualPing, vec![], false).await {
             error!("Failed to store the peer observation: {e}");
         }
 
@@ -498,38 +1641,205 @@ where
             .map(|status| status.last_seen.as_unix_timestamp().saturating_sub(start)))
     }
 
+    fn next_initiation_challenge(&self) -> StartChallenge {
+        self.next_initiation_challenge.fetch_add(1, std::sync::atomic::Ordering::SeqCst)
+    }
+
+    /// Encodes and sends a single [`SessionInitiationMessage`] to `peer` over the regular
+    /// msg-ack packet pipeline, on the reserved [`constants::SESSION_INITIATION_TAG`]. A free
+    /// function over explicit handles (rather than `&self`) so it can be called both from
+    /// [`HoprTransport::new_session`] and from the spawned `SessionsManagement` loop, which only
+    /// holds clones of these handles, not a reference to the whole transport.
+    async fn send_session_initiation_message(
+        process_packet_send: &OnceLock<MsgSender>,
+        path_planner: &helpers::PathPlanner<T>,
+        peer: PeerId,
+        path_options: PathOptions,
+        message: SessionInitiationMessage,
+        packet_queue_timeout: std::time::Duration,
+    ) -> errors::Result<()> {
+        send_tagged_json(
+            process_packet_send,
+            path_planner,
+            peer,
+            path_options,
+            constants::SESSION_INITIATION_TAG,
+            &message,
+            packet_queue_timeout,
+        )
+        .await
+    }
+
+    /// Asks `peer` whether it would accept a session opened under `mode`, without allocating a
+    /// tag or waiting out the full [`constants::SESSION_INITIATION_TIMEOUT`] handshake. Lets a
+    /// caller fail fast on an unsupported mode instead of discovering it 60 seconds into
+    /// [`Self::new_session`].
     #[tracing::instrument(level = "debug", skip(self))]
-    pub async fn new_session(&self, cfg: SessionClientConfig) -> errors::Result<Session> {
-        // TODO: 2.2 session initiation protocol is necessary to establish an application tag instead of this random approach
-        let mut session_id: Option<SessionId> = None;
-        for _ in 0..100 {
-            let random_app_tag = hopr_crypto_random::random_integer(
-                RESERVED_SUBPROTOCOL_TAG_UPPER_LIMIT as u64,
-                Some(RESERVED_SESSION_TAG_UPPER_LIMIT as u64),
-            ) as u16;
-            let id = SessionId::new(random_app_tag, cfg.peer);
-            if !self.sessions.contains_key(&id) {
-                session_id = Some(id);
+    pub async fn is_session_supported(&self, peer: PeerId, mode: SessionMode) -> errors::Result<SessionCapabilityQuery> {
+        let challenge = self.next_initiation_challenge();
+        let (outcome_tx, outcome_rx) = oneshot::channel();
+        self.pending_initiations.lock().unwrap().insert(challenge, outcome_tx);
+
+        let query = SessionInitiationMessage::CapabilityQuery { challenge, mode };
+
+        if let Err(e) = Self::send_session_initiation_message(
+            &self.process_packet_send,
+            &self.path_planner,
+            peer,
+            PathOptions::IntermediatePath(vec![]),
+            query,
+            self.session_cfg.packet_queue_timeout,
+        )
+        .await
+        {
+            self.pending_initiations.lock().unwrap().remove(&challenge);
+            return Err(e);
+        }
+
+        let timeout = sleep(self.session_cfg.session_initiation_timeout).fuse();
+        let wait_for_reply = outcome_rx.fuse();
+        pin_mut!(timeout, wait_for_reply);
+
+        let outcome = match select(timeout, wait_for_reply).await {
+            Either::Left(_) => {
+                self.pending_initiations.lock().unwrap().remove(&challenge);
+                return Err(HoprTransportError::Api("capability query timed out".into()));
+            }
+            Either::Right((outcome, _)) => outcome
+                .map_err(|_| HoprTransportError::Api("capability query responder dropped the handshake".into()))?,
+        };
+
+        match outcome {
+            SessionInitiationOutcome::CapabilityReport { supported, capabilities } => {
+                Ok(SessionCapabilityQuery { supported, capabilities })
             }
+            _ => Err(HoprTransportError::Api(
+                "received an unexpected reply to a capability query".into(),
+            )),
         }
+    }
 
-        let session_id = session_id
-            .ok_or_else(|| errors::HoprTransportError::Api("Failed to generate a non-occupied session ID".into()))?;
+    /// Establishes a new session with `cfg.peer` via a SYN/SYN-ACK handshake on
+    /// [`constants::SESSION_INITIATION_TAG`], instead of blindly picking a random application tag
+    /// and hoping it doesn't collide. Before proposing a tag, checks [`Self::is_session_supported`]
+    /// for a mode derived from `cfg` so an unsupported request fails immediately instead of waiting
+    /// out [`constants::SESSION_INITIATION_TIMEOUT`]. The initiator proposes a tag and its
+    /// capabilities; the responder either confirms that tag or counter-proposes a free one (or
+    /// rejects outright), and only the agreed outcome is inserted into `sessions`. The capabilities
+    /// on the returned [`Session`] reflect what was actually negotiated.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn new_session(&self, cfg: SessionClientConfig) -> errors::Result<Session> {
+        let query = self
+            .is_session_supported(
+                cfg.peer,
+                SessionMode {
+                    reliable: true,
+                    max_segment_size: SESSION_USABLE_MTU_SIZE,
+                    tag_class_limit: self.session_cfg.reserved_session_tag_upper_limit - 1,
+                },
+            )
+            .await?;
+
+        if !query.supported {
+            return Err(HoprTransportError::Api(
+                "peer does not support the requested session mode".into(),
+            ));
+        }
+
+        emit_lifecycle_event(&self.session_events, SessionLifecycleEvent::Initiating { peer: cfg.peer });
+
+        let challenge = self.next_initiation_challenge();
+        let proposed_tag = hopr_crypto_random::random_integer(
+            self.session_cfg.reserved_subprotocol_tag_upper_limit as u64,
+            Some(self.session_cfg.reserved_session_tag_upper_limit as u64),
+        ) as u16;
+
+        let (outcome_tx, outcome_rx) = oneshot::channel();
+        self.pending_initiations.lock().unwrap().insert(challenge, outcome_tx);
+
+        let syn = SessionInitiationMessage::Syn {
+            challenge,
+            proposed_tag,
+            capabilities: cfg.capabilities.clone(),
+        };
+
+        if let Err(e) = Self::send_session_initiation_message(
+            &self.process_packet_send,
+            &self.path_planner,
+            cfg.peer,
+            cfg.path_options.clone(),
+            syn,
+            self.session_cfg.packet_queue_timeout,
+        )
+        .await
+        {
+            self.pending_initiations.lock().unwrap().remove(&challenge);
+            return Err(e);
+        }
+
+        let timeout = sleep(self.session_cfg.session_initiation_timeout).fuse();
+        let wait_for_ack = outcome_rx.fuse();
+        pin_mut!(timeout, wait_for_ack);
+
+        let outcome = match select(timeout, wait_for_ack).await {
+            Either::Left(_) => {
+                self.pending_initiations.lock().unwrap().remove(&challenge);
+                return Err(HoprTransportError::Api(
+                    "session initiation handshake timed out".into(),
+                ));
+            }
+            Either::Right((outcome, _)) => outcome.map_err(|_| {
+                HoprTransportError::Api("session initiation responder dropped the handshake".into())
+            })?,
+        };
+
+        let (session_id, agreed_tag, capabilities) = match outcome {
+            SessionInitiationOutcome::Established { agreed_tag, capabilities } => {
+                (SessionId::new(agreed_tag, cfg.peer), agreed_tag, capabilities)
+            }
+            SessionInitiationOutcome::Rejected(reason) => {
+                return Err(HoprTransportError::Api(format!("session initiation rejected: {reason}")));
+            }
+            SessionInitiationOutcome::CapabilityReport { .. } => {
+                return Err(HoprTransportError::Api(
+                    "received an unexpected reply to a session initiation".into(),
+                ));
+            }
+        };
 
         debug!(
             session_id = tracing::field::debug(session_id),
-            "Generated a new session ID"
+            "Negotiated a new session ID"
         );
 
         let (tx, rx) = futures::channel::mpsc::unbounded::<Box<[u8]>>();
 
         self.sessions.insert(session_id, tx).await;
+        self.session_peers.lock().unwrap().insert(session_id, cfg.peer);
+        self.session_visibility
+            .lock()
+            .unwrap()
+            .insert(session_id, (std::time::Instant::now(), SessionVisibility::Visible));
+        emit_lifecycle_event(
+            &self.session_events,
+            SessionLifecycleEvent::Established {
+                id: session_id,
+                tag: agreed_tag,
+                peer: cfg.peer,
+            },
+        );
+
+        #[cfg(all(feature = "prometheus", not(test)))]
+        {
+            METRIC_SESSIONS_OPENED.increment();
+            METRIC_ACTIVE_SESSIONS.set(self.sessions.entry_count() as f64);
+        }
 
         Ok(Session::new(
             session_id,
             self.me,
             cfg.path_options,
-            cfg.capabilities,
+            capabilities,
             Arc::new(helpers::MessageSender::new(
                 self.process_packet_send.clone(),
                 self.path_planner.clone(),
@@ -538,6 +1848,93 @@ where
         ))
     }
 
+    /// Opens a one-directional response stream to `peer` via the same SYN/SYN-ACK handshake as
+    /// [`Self::new_session`] (using [`SessionInitiationMessage::SynStream`] in place of `Syn`), so
+    /// that a single logical request can get back a reply larger than one packet without inventing
+    /// bespoke chunking over an ad-hoc tag. The returned `Stream` yields payloads in order as the
+    /// responder's [`StreamResponder`] sends them, and ends once the responder calls
+    /// [`StreamResponder::finish`] and every payload up to that point has been delivered; frames
+    /// that arrive out of order are reassembled, and frames beyond [`RESPONSE_STREAM_WINDOW`] are
+    /// dropped to bound the reassembly buffer.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn open_response_stream(
+        &self,
+        peer: PeerId,
+        path_options: PathOptions,
+    ) -> errors::Result<(SessionId, impl futures::Stream<Item = Box<[u8]>>)> {
+        let challenge = self.next_initiation_challenge();
+        let proposed_tag = hopr_crypto_random::random_integer(
+            self.session_cfg.reserved_subprotocol_tag_upper_limit as u64,
+            Some(self.session_cfg.reserved_session_tag_upper_limit as u64),
+        ) as u16;
+
+        let (outcome_tx, outcome_rx) = oneshot::channel();
+        self.pending_initiations.lock().unwrap().insert(challenge, outcome_tx);
+
+        let syn = SessionInitiationMessage::SynStream { challenge, proposed_tag };
+
+        if let Err(e) = Self::send_session_initiation_message(
+            &self.process_packet_send,
+            &self.path_planner,
+            peer,
+            path_options,
+            syn,
+            self.session_cfg.packet_queue_timeout,
+        )
+        .await
+        {
+            self.pending_initiations.lock().unwrap().remove(&challenge);
+            return Err(e);
+        }
+
+        let timeout = sleep(self.session_cfg.session_initiation_timeout).fuse();
+        let wait_for_ack = outcome_rx.fuse();
+        pin_mut!(timeout, wait_for_ack);
+
+        let outcome = match select(timeout, wait_for_ack).await {
+            Either::Left(_) => {
+                self.pending_initiations.lock().unwrap().remove(&challenge);
+                return Err(HoprTransportError::Api(
+                    "response stream initiation handshake timed out".into(),
+                ));
+            }
+            Either::Right((outcome, _)) => outcome.map_err(|_| {
+                HoprTransportError::Api("response stream initiation responder dropped the handshake".into())
+            })?,
+        };
+
+        let session_id = match outcome {
+            SessionInitiationOutcome::Established { agreed_tag, .. } => SessionId::new(agreed_tag, peer),
+            SessionInitiationOutcome::Rejected(reason) => {
+                return Err(HoprTransportError::Api(format!("response stream initiation rejected: {reason}")));
+            }
+            SessionInitiationOutcome::CapabilityReport { .. } => {
+                return Err(HoprTransportError::Api(
+                    "received an unexpected reply to a response stream initiation".into(),
+                ));
+            }
+        };
+
+        debug!(
+            session_id = tracing::field::debug(session_id),
+            "Negotiated a new response stream"
+        );
+
+        let (tx, rx) = futures::channel::mpsc::unbounded::<Box<[u8]>>();
+
+        self.response_streams
+            .insert(
+                session_id,
+                ResponseStreamSink {
+                    tx,
+                    reorder: Arc::new(Mutex::new(ReorderState::default())),
+                },
+            )
+            .await;
+
+        Ok((session_id, rx))
+    }
+
     #[tracing::instrument(level = "info", skip(self, msg), fields(uuid = uuid::Uuid::new_v4().to_string()))]
     pub async fn send_message(
         &self,
@@ -568,6 +1965,9 @@ where
             HoprTransportError::Api("send msg: failed because message processing is not yet initialized".into())
         })?;
 
+        #[cfg(all(feature = "prometheus", not(test)))]
+        METRIC_MESSAGES_OUT.increment();
+
         sender
             .send_packet(app_data, path)
             .await
@@ -597,13 +1997,18 @@ where
             return Err(ProtocolError::ChannelClosed.into());
         }
 
-        Ok(Arc::new(TicketAggregatorProxy::new(
+        let result = Arc::new(TicketAggregatorProxy::new(
             self.db.clone(),
             self.process_ticket_aggregate.clone(),
             self.cfg.protocol.ticket_aggregation.timeout,
         ))
         .aggregate_tickets(&entry.get_id(), Default::default())
-        .await?)
+        .await;
+
+        #[cfg(all(feature = "prometheus", not(test)))]
+        METRIC_TICKET_AGGREGATIONS.increment(&[if result.is_ok() { "success" } else { "failure" }]);
+
+        Ok(result?)
     }
 
     #[tracing::instrument(level = "debug", skip(self))]
@@ -664,14 +2069,21 @@ where
     }
 
     #[tracing::instrument(level = "debug", skip(self))]
-    pub fn announceable_multiaddresses(&self) -> Vec<Multiaddr> {
+    pub async fn announceable_multiaddresses(&self) -> Vec<Multiaddr> {
         let mut mas = self
             .local_multiaddresses()
+            .await
             .into_iter()
             .filter(|ma| {
                 hopr_transport_p2p::multiaddrs::is_supported(ma)
                     && (self.cfg.transport.announce_local_addresses || !hopr_transport_p2p::multiaddrs::is_private(ma))
             })
+            .filter(|ma| {
+                // only announce addresses AutoNAT has actually confirmed reachable, plus whatever
+                // the operator has explicitly configured as externally reachable regardless
+                self.cfg.transport.external_multiaddresses.contains(ma)
+                    || self.nat_status.status(ma) == AddressReachability::Public
+            })
             .map(|ma| strip_p2p_protocol(&ma))
             .filter(|v| !v.is_empty())
             .collect::<Vec<_>>();
@@ -692,8 +2104,67 @@ where
         mas
     }
 
-    pub fn local_multiaddresses(&self) -> Vec<Multiaddr> {
-        self.my_multiaddresses.clone()
+    /// This node's own configured/observed multiaddrs, with any `/dnsaddr/<name>` entry expanded
+    /// into its concrete addresses (see [`dnsaddr::resolve_dnsaddr`]) before the caller sees it.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn local_multiaddresses(&self) -> Vec<Multiaddr> {
+        let mut expanded = Vec::with_capacity(self.my_multiaddresses.len());
+
+        for ma in &self.my_multiaddresses {
+            expanded.extend(dnsaddr::resolve_dnsaddr(ma, self.dnsaddr_resolver.as_ref(), &self.dnsaddr_cache).await);
+        }
+
+        expanded
+    }
+
+    /// Records the outcome of asking some peer to dial us back on `addr`, feeding
+    /// [`NatStatusTracker`]'s confidence counter. Meant to be called by the (swarm-level) AutoNAT
+    /// prober each time it completes one such dial-back attempt.
+    pub fn report_address_reachability(&self, addr: Multiaddr, reachable: bool) {
+        self.nat_status.record_vote(addr, reachable);
+    }
+
+    /// The node's current inferred NAT status, aggregated over all candidate addresses probed so
+    /// far by [`Self::report_address_reachability`].
+    pub fn nat_status(&self) -> NatStatus {
+        self.nat_status.global_status()
+    }
+
+    /// Whether mDNS local-peer discovery is currently active. Off by default for privacy; see
+    /// [`Self::set_mdns_enabled`].
+    pub fn mdns_enabled(&self) -> bool {
+        self.mdns_enabled.load(std::sync::atomic::Ordering::Relaxed)
+    }
+
+    /// Turns mDNS local-peer discovery on or off at runtime, without requiring a restart. While
+    /// disabled, [`Self::on_mdns_peer_discovered`] ignores whatever the (swarm-level) mDNS
+    /// behaviour reports.
+    pub fn set_mdns_enabled(&self, enabled: bool) {
+        self.mdns_enabled.store(enabled, std::sync::atomic::Ordering::Relaxed);
+    }
+
+    /// Called by the swarm's mDNS behaviour whenever it observes `peer` advertising `addr` on the
+    /// local network. Records the observation into the same network store that backs
+    /// [`Self::network_observed_multiaddresses`] (tagged [`AddressSource::Mdns`]) when mDNS
+    /// discovery is enabled, and returns whether the caller should go on to actually dial `addr`:
+    /// `true` only if `announce_local_addresses` is also set, so a locally-discovered address is
+    /// never auto-dialed while the node's privacy posture says local addresses shouldn't be
+    /// exposed in the first place.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn on_mdns_peer_discovered(&self, peer: &PeerId, addr: Multiaddr) -> bool {
+        if !self.mdns_enabled() {
+            return false;
+        }
+
+        if let Err(e) = self
+            .network
+            .add_with_source(peer, PeerOrigin::Mdns, vec![addr], false, AddressSource::Mdns)
+            .await
+        {
+            error!("Failed to store mDNS-discovered peer observation: {e}");
+        }
+
+        self.cfg.transport.announce_local_addresses
     }
 
     #[tracing::instrument(level = "debug", skip(self))]
@@ -711,9 +2182,30 @@ where
         self.network.health().await
     }
 
+    /// Subscribes to topology changes as a `Stream` of [`NetworkEvent`]s, for consumers that want
+    /// to react to changes instead of repeatedly calling [`Self::network_connected_peers`] and
+    /// [`Self::network_peer_info`] and diffing the results themselves.
+    pub fn subscribe_network_events(&self) -> impl futures::Stream<Item = NetworkEvent> {
+        self.network.subscribe_events()
+    }
+
+    /// Subscribes to [`SessionLifecycleEvent`]s for every session this transport opens or accepts,
+    /// so a caller can react to teardown (and rebuild proactively, or surface metrics) instead of
+    /// polling [`Self::new_session`]'s callers for liveness.
+    pub fn subscribe_session_events(&self) -> impl futures::Stream<Item = SessionLifecycleEvent> {
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        self.session_events.lock().unwrap().push(tx);
+        rx
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     pub async fn network_connected_peers(&self) -> errors::Result<Vec<PeerId>> {
-        Ok(self.network.peer_filter(|peer| async move { Some(peer.id.1) }).await?)
+        let peers = self.network.peer_filter(|peer| async move { Some(peer.id.1) }).await?;
+
+        #[cfg(all(feature = "prometheus", not(test)))]
+        METRIC_SWARM_CONNECTED_PEERS.set(peers.len() as f64);
+
+        Ok(peers)
     }
 
     #[tracing::instrument(level = "debug", skip(self))]
@@ -721,6 +2213,39 @@ where
         Ok(self.network.get(peer).await?)
     }
 
+    /// `peer`'s rolling RTT estimate, for preferring low-latency peers without reaching into the
+    /// raw [`PeerStatus`] returned by [`Self::network_peer_info`].
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn network_peer_rtt(&self, peer: &PeerId) -> errors::Result<Option<std::time::Duration>> {
+        Ok(self.network.rtt_estimate(peer).await?)
+    }
+
+    /// The direction of `peer`'s currently active connection.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn network_peer_direction(&self, peer: &PeerId) -> errors::Result<Option<ConnectionDirection>> {
+        Ok(self.network.connection_direction(peer).await?)
+    }
+
+    /// How `addr` was learned about for `peer`: locally via mDNS, by us dialing out, on an
+    /// inbound connection, configured manually, or relayed via peer exchange.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub fn network_peer_address_source(&self, peer: &PeerId, addr: &Multiaddr) -> Option<AddressSource> {
+        self.network.address_source(peer, addr)
+    }
+
+    /// Called by the swarm's identify handler once it observes `peer` advertising a network ID
+    /// that does not match [`config::TransportConfig::network_id`]. Unlike a regular failed probe,
+    /// a mismatched peer is never a member of this deployment to begin with, so it is dropped from
+    /// [`Network`] outright instead of being downgraded the way an unreachable peer would be.
+    #[tracing::instrument(level = "debug", skip(self))]
+    pub async fn on_peer_identity_mismatch(&self, peer: &PeerId) -> errors::Result<()> {
+        warn!(
+            peer_id = tracing::field::debug(*peer),
+            "Rejecting peer advertising a mismatched network ID"
+        );
+        Ok(self.network.remove(peer).await?)
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     pub async fn ticket_statistics(&self) -> errors::Result<TicketStatistics> {
         let ticket_stats = self.db.get_ticket_statistics(None).await?;
@@ -763,3 +2288,70 @@ where
             .collect())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn addr(suffix: u16) -> Multiaddr {
+        format!("/ip4/10.0.0.1/tcp/{suffix}").parse().unwrap()
+    }
+
+    #[test]
+    fn record_vote_should_reset_the_streak_on_a_disagreeing_vote() {
+        let tracker = NatStatusTracker::default();
+        let a = addr(1);
+
+        tracker.record_vote(a.clone(), true);
+        tracker.record_vote(a.clone(), true);
+        // Disagreeing vote resets the streak instead of merely failing to extend it.
+        tracker.record_vote(a.clone(), false);
+        tracker.record_vote(a.clone(), true);
+        tracker.record_vote(a.clone(), true);
+
+        // Only 2 consecutive agreeing votes so far: below the confirmation threshold.
+        assert_eq!(tracker.status(&a), AddressReachability::Unknown);
+    }
+
+    #[test]
+    fn record_vote_should_only_confirm_once_the_threshold_streak_is_reached() {
+        let tracker = NatStatusTracker::default();
+        let a = addr(2);
+
+        for _ in 0..NAT_STATUS_CONFIDENCE_THRESHOLD - 1 {
+            tracker.record_vote(a.clone(), true);
+        }
+        assert_eq!(tracker.status(&a), AddressReachability::Unknown);
+
+        tracker.record_vote(a.clone(), true);
+        assert_eq!(tracker.status(&a), AddressReachability::Public);
+    }
+
+    #[test]
+    fn global_status_should_prefer_public_over_private_across_addresses() {
+        let tracker = NatStatusTracker::default();
+        let public_addr = addr(3);
+        let private_addr = addr(4);
+
+        for _ in 0..NAT_STATUS_CONFIDENCE_THRESHOLD {
+            tracker.record_vote(private_addr.clone(), false);
+        }
+        assert_eq!(tracker.global_status(), NatStatus::Private);
+
+        for _ in 0..NAT_STATUS_CONFIDENCE_THRESHOLD {
+            tracker.record_vote(public_addr.clone(), true);
+        }
+
+        // One confirmed Public address is enough to report Public overall, even though
+        // another address is confirmed Private.
+        assert_eq!(tracker.global_status(), NatStatus::Public);
+    }
+
+    #[test]
+    fn global_status_should_be_unknown_until_any_address_is_confirmed() {
+        let tracker = NatStatusTracker::default();
+        tracker.record_vote(addr(5), true);
+
+        assert_eq!(tracker.global_status(), NatStatus::Unknown);
+    }
+}
@@ -1,10 +1,12 @@
-use futures::future::{select, Either};
-use futures::pin_mut;
+use futures::stream::Stream;
 use futures::FutureExt;
-use log::{trace, warn};
+use log::trace;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
 use std::time::Duration;
 
-use async_std::task::sleep;
 use hopr_platform::time::native::current_time;
 use hopr_primitive_types::prelude::AsUnixTimestamp;
 
@@ -12,31 +14,423 @@ fn get_timestamp() -> Duration {
     current_time().as_unix_timestamp()
 }
 
+/// Abstracts the time source used by timers in this crate, so they can be driven
+/// deterministically in tests instead of relying on real OS delays.
+#[async_trait::async_trait]
+pub trait Clock: Send + Sync {
+    /// Returns the current time as seen by this clock.
+    fn now(&self) -> Duration;
+
+    /// Suspends the caller until `duration` has elapsed on this clock.
+    async fn sleep(&self, duration: Duration);
+}
+
+/// The default [`Clock`] backed by the real OS time and an actual sleep.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NativeClock;
+
+#[async_trait::async_trait]
+impl Clock for NativeClock {
+    fn now(&self) -> Duration {
+        get_timestamp()
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        async_std::task::sleep(duration).await
+    }
+}
+
+struct MockClockInner {
+    now: Duration,
+    wakers: Vec<(Duration, Waker)>,
+}
+
+/// A [`Clock`] whose time only moves forward when explicitly told to via [`MockClock::advance`],
+/// allowing tests to pause time and deterministically assert on timer behavior.
+#[derive(Clone)]
+pub struct MockClock {
+    inner: Arc<Mutex<MockClockInner>>,
+}
+
+impl MockClock {
+    pub fn new(start: Duration) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(MockClockInner {
+                now: start,
+                wakers: Vec::new(),
+            })),
+        }
+    }
+
+    /// Advances this clock by `by`, waking any sleeper whose deadline has now passed.
+    pub fn advance(&self, by: Duration) {
+        let mut inner = self.inner.lock().expect("mock clock lock poisoned");
+        inner.now += by;
+        let now = inner.now;
+        inner.wakers.retain(|(deadline, waker)| {
+            if *deadline <= now {
+                waker.wake_by_ref();
+                false
+            } else {
+                true
+            }
+        });
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new(Duration::ZERO)
+    }
+}
+
+#[async_trait::async_trait]
+impl Clock for MockClock {
+    fn now(&self) -> Duration {
+        self.inner.lock().expect("mock clock lock poisoned").now
+    }
+
+    async fn sleep(&self, duration: Duration) {
+        MockClockSleep {
+            clock: self.clone(),
+            deadline: self.now() + duration,
+        }
+        .await
+    }
+}
+
+struct MockClockSleep {
+    clock: MockClock,
+    deadline: Duration,
+}
+
+impl Future for MockClockSleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut inner = self.clock.inner.lock().expect("mock clock lock poisoned");
+        if inner.now >= self.deadline {
+            Poll::Ready(())
+        } else {
+            inner.wakers.push((self.deadline, cx.waker().clone()));
+            Poll::Pending
+        }
+    }
+}
+
+/// Governs what happens when a tick deadline is missed, e.g. because the previous
+/// tick's action took longer than `period` to complete.
+///
+/// Modeled after tokio's `MissedTickBehavior`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum MissedTickBehavior {
+    /// Fire immediately for each missed deadline, preserving the original phase by
+    /// advancing the next deadline by whole multiples of `period` until it lies in the future.
+    #[default]
+    Burst,
+    /// Reset the next deadline to `now + period`, shifting the phase of subsequent ticks.
+    Delay,
+    /// Skip all missed ticks and realign to the next deadline that is on the original grid.
+    Skip,
+}
+
+impl MissedTickBehavior {
+    fn next_deadline(&self, scheduled: Duration, now: Duration, start: Duration, period: Duration) -> Duration {
+        match self {
+            // Only advance by a single `period` here; if that still leaves `next_deadline` in
+            // the past, `remaining` below saturates to zero, so the next `sleep` resolves
+            // immediately and `poll_tick` is driven again on the following `tick()` call. That
+            // way each missed deadline still fires exactly once, one at a time, rather than
+            // collapsing every missed period into a single jump to the first future deadline.
+            MissedTickBehavior::Burst => scheduled + period,
+            MissedTickBehavior::Delay => now + period,
+            MissedTickBehavior::Skip => {
+                let elapsed = now.saturating_sub(start);
+                let period_nanos = period.as_nanos().max(1);
+                let remainder = elapsed.as_nanos() % period_nanos;
+                now + period - Duration::from_nanos(remainder as u64)
+            }
+        }
+    }
+}
+
+/// A [`Stream`] that produces a timestamp every `period`, with configurable behavior for
+/// what happens when a tick is missed.
+///
+/// Unlike naively re-sleeping for `period` on every loop iteration, `Interval` tracks an
+/// absolute `next_deadline` so the tick phase does not drift under repeated scheduling jitter.
+/// Timing is driven by a `&Clock` reference rather than an owned/`Arc`-cloned clock, so the
+/// same clock instance can be shared by the caller (e.g. to advance it in tests) without
+/// this type taking ownership of it.
+pub struct Interval<'a> {
+    clock: &'a dyn Clock,
+    start: Duration,
+    period: Duration,
+    next_deadline: Duration,
+    behavior: MissedTickBehavior,
+    sleep: Option<Pin<Box<dyn Future<Output = ()> + Send + 'a>>>,
+    /// Number of ticks remaining before this interval is terminated, or `None` if unbounded.
+    remaining: Option<u64>,
+}
+
+impl<'a> Interval<'a> {
+    pub fn new(clock: &'a dyn Clock, period: Duration, behavior: MissedTickBehavior) -> Self {
+        let start = clock.now();
+        let next_deadline = start + period;
+        Self {
+            clock,
+            start,
+            period,
+            next_deadline,
+            behavior,
+            sleep: None,
+            remaining: None,
+        }
+    }
+
+    /// Bounds this interval to at most `limit` more ticks, after which it becomes terminated
+    /// (see [`futures::stream::FusedStream`]) instead of ticking forever.
+    pub fn take(mut self, limit: u64) -> Self {
+        self.remaining = Some(limit);
+        self
+    }
+
+    /// Resets the interval so the next tick fires after one full `period` from now.
+    pub fn reset(&mut self) {
+        self.start = self.clock.now();
+        self.next_deadline = self.start + self.period;
+        self.sleep = None;
+    }
+
+    /// Awaits the next tick of this interval.
+    pub async fn tick(&mut self) -> Duration {
+        futures::future::poll_fn(|cx| self.poll_tick(cx)).await
+    }
+
+    fn poll_tick(&mut self, cx: &mut Context<'_>) -> Poll<Duration> {
+        // The sleep towards `next_deadline` is created by the poll that starts waiting on it, so
+        // its length is measured from that moment rather than from when the last tick fired.
+        let (clock, deadline) = (self.clock, self.next_deadline);
+        let sleep = self.sleep.get_or_insert_with(|| {
+            let remaining = deadline.saturating_sub(clock.now());
+            Box::pin(clock.sleep(remaining))
+        });
+        match sleep.as_mut().poll(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(()) => {
+                let now = self.clock.now();
+                let fired = self.next_deadline;
+                self.next_deadline = self
+                    .behavior
+                    .next_deadline(self.next_deadline, now, self.start, self.period);
+                self.sleep = None;
+
+                Poll::Ready(fired)
+            }
+        }
+    }
+}
+
+impl<'a> Stream for Interval<'a> {
+    type Item = Duration;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.is_terminated() {
+            return Poll::Ready(None);
+        }
+
+        self.poll_tick(cx).map(|fired| {
+            if let Some(remaining) = self.remaining.as_mut() {
+                *remaining -= 1;
+            }
+            Some(fired)
+        })
+    }
+}
+
+impl<'a> futures::stream::FusedStream for Interval<'a> {
+    /// An unbounded interval is never terminated; one created with [`Interval::take`] becomes
+    /// terminated once its final tick has fired, so it can be safely polled again (e.g. inside
+    /// `select!`) without producing further items.
+    fn is_terminated(&self) -> bool {
+        self.remaining == Some(0)
+    }
+}
+
+/// Error returned by [`with_timeout`] when the wrapped future did not complete in time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeoutError;
+
+impl std::fmt::Display for TimeoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "future did not complete within the allotted timeout")
+    }
+}
+
+impl std::error::Error for TimeoutError {}
+
+/// Races `fut` against a `timeout` sleep on `clock`. Returns `Ok` with the future's output if
+/// it completes first, or `Err(TimeoutError)` if the timeout elapses first, dropping `fut`.
+pub async fn with_timeout<F: std::future::Future>(
+    clock: &dyn Clock,
+    timeout: Duration,
+    fut: F,
+) -> std::result::Result<F::Output, TimeoutError> {
+    let delay = clock.sleep(timeout).fuse();
+    let fut = fut.fuse();
+
+    futures::pin_mut!(delay, fut);
+
+    match futures::future::select(delay, fut).await {
+        futures::future::Either::Left(_) => Err(TimeoutError),
+        futures::future::Either::Right((output, _)) => Ok(output),
+    }
+}
+
 /// Represents a periodically timed ticks in a loop with the given period.
-/// Could be later extended, so it supports multiple different periods and multiple actions.
-pub async fn execute_on_tick<F>(cycle: Duration, action: impl Fn() -> F)
+///
+/// This is implemented on top of [`Interval`] using [`MissedTickBehavior::Burst`], which
+/// fires immediately for every missed deadline instead of the previous undefined behavior
+/// where an overrunning action would silently collapse the remaining sleep time.
+pub async fn execute_on_tick<F>(clock: &dyn Clock, cycle: Duration, action: impl Fn() -> F)
 where
     F: std::future::Future<Output = ()> + Send,
 {
+    let mut interval = Interval::new(clock, cycle, MissedTickBehavior::Burst);
+
     loop {
-        let start = get_timestamp();
+        interval.tick().await;
 
-        let timeout = sleep(cycle).fuse();
-        let todo = (action)().fuse();
+        let start = clock.now();
+        (action)().await;
+        trace!(
+            "Universal timer action took: {}ms",
+            clock.now().saturating_sub(start).as_millis()
+        );
+    }
+}
 
-        pin_mut!(timeout, todo);
+/// Like [`execute_on_tick`], but bounds each invocation of `action` with `action_timeout`.
+/// When an action times out, `on_timeout` is invoked instead of only logging a warning,
+/// so callers can observe and react to a stuck action.
+pub async fn execute_on_tick_with_timeout<F>(
+    clock: &dyn Clock,
+    cycle: Duration,
+    action_timeout: Duration,
+    action: impl Fn() -> F,
+    on_timeout: impl Fn(TimeoutError),
+) where
+    F: std::future::Future<Output = ()> + Send,
+{
+    let mut interval = Interval::new(clock, cycle, MissedTickBehavior::Burst);
 
-        match select(timeout, todo).await {
-            Either::Left(_) => warn!("Timer tick interrupted by timeout"),
-            Either::Right(_) => {
-                trace!("Timer tick finished");
+    loop {
+        interval.tick().await;
 
-                let action_duration = get_timestamp().saturating_sub(start);
-                if let Some(remaining) = cycle.checked_sub(action_duration) {
-                    trace!("Universal timer sleeping for: {}ms", remaining.as_millis());
-                    sleep(remaining).await
-                }
-            }
-        };
+        let start = clock.now();
+        if let Err(e) = with_timeout(clock, action_timeout, (action)()).await {
+            (on_timeout)(e);
+        }
+        trace!(
+            "Universal timer action took: {}ms",
+            clock.now().saturating_sub(start).as_millis()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[async_std::test]
+    async fn mock_clock_should_not_advance_on_its_own() {
+        let clock = MockClock::new(Duration::ZERO);
+        assert_eq!(clock.now(), Duration::ZERO);
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(clock.now(), Duration::from_secs(1));
+    }
+
+    #[async_std::test]
+    async fn burst_interval_should_fire_once_per_missed_period_to_catch_up() {
+        let clock = MockClock::new(Duration::ZERO);
+        let mut interval = Interval::new(&clock, Duration::from_secs(1), MissedTickBehavior::Burst);
+
+        // Advance the paused clock past 3 periods before polling, so each `tick()` below
+        // resolves immediately instead of requiring a concurrent waker to drive it.
+        clock.advance(Duration::from_secs(3));
+
+        let mut fired = Vec::new();
+        for _ in 0..3 {
+            fired.push(interval.tick().await);
+        }
+
+        assert_eq!(fired, vec![Duration::from_secs(1), Duration::from_secs(2), Duration::from_secs(3)]);
+    }
+
+    #[async_std::test]
+    async fn burst_interval_should_wait_for_the_clock_once_caught_up() {
+        let clock = MockClock::new(Duration::ZERO);
+        let mut interval = Interval::new(&clock, Duration::from_secs(1), MissedTickBehavior::Burst);
+
+        // Three missed periods to catch up on, same as the test above.
+        clock.advance(Duration::from_secs(3));
+        for expected in [1, 2, 3] {
+            assert_eq!(interval.tick().await, Duration::from_secs(expected));
+        }
+
+        // Caught up now: the next tick must actually wait for the clock to reach the next
+        // deadline rather than firing again immediately.
+        let mut next_tick = interval.tick();
+        assert_eq!(
+            futures::poll!(&mut next_tick),
+            std::task::Poll::Pending,
+            "tick should not resolve before the clock reaches the next deadline"
+        );
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(next_tick.await, Duration::from_secs(4));
+    }
+
+    #[async_std::test]
+    async fn skip_interval_should_realign_to_the_grid_after_a_large_gap() {
+        let clock = MockClock::new(Duration::ZERO);
+        let mut interval = Interval::new(&clock, Duration::from_secs(1), MissedTickBehavior::Skip);
+
+        clock.advance(Duration::from_secs(5));
+
+        // Skip realigns to the next on-grid deadline rather than firing 5 times.
+        assert_eq!(interval.tick().await, Duration::from_secs(1));
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(interval.tick().await, Duration::from_secs(6));
+    }
+
+    #[async_std::test]
+    async fn bounded_interval_terminates_after_its_final_tick() {
+        use futures::stream::FusedStream;
+        use futures::StreamExt;
+
+        let clock = MockClock::new(Duration::ZERO);
+        let mut interval = Interval::new(&clock, Duration::from_secs(1), MissedTickBehavior::Burst).take(2);
+
+        clock.advance(Duration::from_secs(10));
+
+        assert!(interval.next().await.is_some());
+        assert!(!interval.is_terminated());
+        assert!(interval.next().await.is_some());
+        assert!(interval.is_terminated());
+        assert!(interval.next().await.is_none());
+    }
+
+    #[async_std::test]
+    async fn with_timeout_should_return_the_output_when_the_future_wins() {
+        let clock = NativeClock;
+        let result = with_timeout(&clock, Duration::from_secs(5), async { 42 }).await;
+        assert_eq!(result, Ok(42));
+    }
+
+    #[async_std::test]
+    async fn with_timeout_should_error_when_the_timeout_wins() {
+        let clock = NativeClock;
+        let result = with_timeout(&clock, Duration::from_millis(1), futures::future::pending::<()>()).await;
+        assert_eq!(result, Err(TimeoutError));
     }
 }
@@ -1,11 +1,14 @@
 use std::collections::hash_set::HashSet;
+use std::collections::HashMap;
+use std::sync::Mutex;
 use std::time::{Duration, SystemTime};
 
+use futures::channel::mpsc::UnboundedSender;
 use futures::StreamExt;
 use hopr_primitive_types::traits::SaturatingSub;
 use libp2p_identity::PeerId;
 
-use multiaddr::Multiaddr;
+use multiaddr::{Multiaddr, Protocol};
 use tracing::debug;
 
 pub use hopr_db_api::peers::{HoprDbPeersOperations, PeerOrigin, PeerSelector, PeerStatus, Stats};
@@ -33,6 +36,10 @@ lazy_static::lazy_static! {
         "hopr_time_to_green_sec",
         "Time it takes for a node to transition to the GREEN network state"
     ).unwrap();
+    static ref METRIC_PEER_LATENCY_P50: SimpleGauge =
+        SimpleGauge::new("hopr_peer_latency_p50_ms", "Median smoothed RTT across known peers").unwrap();
+    static ref METRIC_PEER_LATENCY_P95: SimpleGauge =
+        SimpleGauge::new("hopr_peer_latency_p95_ms", "95th percentile smoothed RTT across known peers").unwrap();
 }
 
 /// Network health represented with colors, where green is the best and red
@@ -51,6 +58,80 @@ pub enum Health {
     Green = 4,
 }
 
+/// How we learned about one of a peer's multiaddresses: discovered on the LAN via mDNS, observed
+/// by dialing out to the peer ourselves, observed on an inbound connection the peer opened to us,
+/// configured by the operator, or relayed to us by another peer via peer exchange. Tracked
+/// alongside [`PeerStatus`] (rather than as a field on it, since `PeerStatus` is owned by
+/// `hopr_db_api`) in [`Network`]'s own `address_sources` table and readable via
+/// [`Network::address_source`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display)]
+pub enum AddressSource {
+    Mdns,
+    Dialer,
+    Listener,
+    Manual,
+    Dht,
+}
+
+impl AddressSource {
+    /// The source to assume for an address reported alongside `origin`, for callers of
+    /// [`Network::add`] that have no more specific source to record. Mirrors how `origin` was
+    /// already being used to describe how a peer itself was learned about.
+    fn inferred(origin: &PeerOrigin, inbound: bool) -> Self {
+        match origin {
+            PeerOrigin::PeerExchange(_) => AddressSource::Dht,
+            PeerOrigin::Initialization | PeerOrigin::ManualPing => AddressSource::Manual,
+            _ => {
+                if inbound {
+                    AddressSource::Listener
+                } else {
+                    AddressSource::Dialer
+                }
+            }
+        }
+    }
+}
+
+/// Direction of a peer's currently active connection, as its own type rather than the bare
+/// [`PeerStatus::is_inbound`] `bool` for call sites that want to print or branch on it by name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display)]
+pub enum ConnectionDirection {
+    Inbound,
+    Outbound,
+}
+
+impl From<bool> for ConnectionDirection {
+    fn from(is_inbound: bool) -> Self {
+        if is_inbound {
+            ConnectionDirection::Inbound
+        } else {
+            ConnectionDirection::Outbound
+        }
+    }
+}
+
+/// Topology change observable via [`Network::subscribe_events`], for consumers that want to react
+/// to changes instead of repeatedly polling `network_connected_peers()`/`network_peer_info()` and
+/// diffing the results themselves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetworkEvent {
+    PeerConnected(PeerId),
+    PeerDisconnected(PeerId),
+    /// Emitted by [`Network::sweep_expired_peers`] right before a stale peer is pruned, so a
+    /// subscriber never sees it silently vanish from `network_connected_peers()`.
+    PeerExpired(PeerId),
+    MultiaddrsUpdated(PeerId, Vec<Multiaddr>),
+}
+
+/// Outcome of requesting a connection slot via [`Network::reserve_slot`].
+#[derive(Debug, Clone, PartialEq, strum::Display)]
+pub enum SlotDecision {
+    /// A slot is available for the candidate, freeing it by evicting the given peer first if set.
+    Accept(Option<NetworkTriggeredEvent>),
+    /// All slots for the requested direction are held by peers that must not be evicted.
+    Reject,
+}
+
 /// Events generated by the [Network] object allowing it
 /// to physically interact with external systems,
 /// including the transport mechanism.
@@ -58,10 +139,63 @@ pub enum Health {
 pub enum NetworkTriggeredEvent {
     CloseConnection(PeerId),
     UpdateQuality(PeerId, f64),
+    /// Coordinate a DCUtR-style simultaneous dial to directly reach a non-public `peer` that is
+    /// currently only known via the given relay `Multiaddr`.
+    AttemptHolePunch { peer: PeerId, relay: Multiaddr },
+    /// The peer's reputation fell below [`NetworkConfig::reputation_banned_threshold`] and it has
+    /// been banned until the given instant; callers should close any existing connection and
+    /// refuse new ones until then.
+    Ban(PeerId, SystemTime),
+    /// The peer's reconnection backoff (see [`Network::update`]) has elapsed; the caller should
+    /// attempt to dial it again.
+    AttemptReconnect(PeerId),
+}
+
+/// A jittered variant of `delay`, scaled by up to ±25% based on `peer` and `attempts` so that
+/// many peers whose backoffs were started around the same time do not all retry in lockstep.
+/// Deterministic given its inputs, rather than relying on a global RNG, so reconnection timing
+/// stays reproducible for tests and across restarts once `attempts` is restored from `db`.
+fn jittered_delay(peer: &PeerId, attempts: u32, delay: Duration) -> Duration {
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    peer.hash(&mut hasher);
+    attempts.hash(&mut hasher);
+
+    let unit = (hasher.finish() % 10_000) as f64 / 10_000.0; // [0, 1)
+    let factor = 1.0 + (unit - 0.5) * 0.5; // [0.75, 1.25)
+
+    Duration::from_secs_f64(delay.as_secs_f64() * factor)
+}
+
+/// A coarse network-prefix key for `addrs` (the first two octets of an IPv4 address, or the
+/// first segment of an IPv6 address), used to spread inbound-slot eviction protection across
+/// distinct networks rather than letting many peers from the same range all count as "diverse".
+fn address_prefix(addrs: &[Multiaddr]) -> Option<String> {
+    addrs.iter().find_map(|addr| {
+        addr.iter().find_map(|protocol| match protocol {
+            Protocol::Ip4(ip) => Some(format!("4:{}.{}", ip.octets()[0], ip.octets()[1])),
+            Protocol::Ip6(ip) => Some(format!("6:{:x}", ip.segments()[0])),
+            _ => None,
+        })
+    })
 }
 
-/// Calculate the health factor for network from the available stats
-fn health_from_stats(stats: &Stats, is_public: bool) -> Health {
+/// Returns the first relayed (`/p2p-circuit`) address among `addrs`, if any, indicating the peer
+/// can be reached through a relay and a hole-punch attempt is worthwhile. Direct addresses also
+/// present in `addrs` do not change the result.
+fn relay_hop_address(addrs: &[Multiaddr]) -> Option<Multiaddr> {
+    addrs
+        .iter()
+        .find(|addr| addr.iter().any(|protocol| matches!(protocol, Protocol::P2pCircuit)))
+        .cloned()
+}
+
+/// Calculate the health factor for network from the available stats. A reachable priority peer
+/// (see [`Network::set_priority`]) guarantees at least [`Health::Yellow`], since the node is
+/// known to still be connected to infrastructure it considers critical even if every other
+/// quality signal looks bad.
+fn health_from_stats(stats: &Stats, is_public: bool, has_reachable_priority_peer: bool) -> Health {
     let mut health = Health::Red;
 
     if stats.bad_quality_public > 0 {
@@ -76,6 +210,10 @@ fn health_from_stats(stats: &Stats, is_public: bool) -> Health {
         };
     }
 
+    if has_reachable_priority_peer && health < Health::Yellow {
+        health = Health::Yellow;
+    }
+
     health
 }
 
@@ -93,6 +231,19 @@ where
     db: T,
     #[cfg(all(feature = "prometheus", not(test)))]
     started_at: Duration,
+    /// Per-source contribution window for [`Network::ingest_exchanged_peers`], keyed by the PEX
+    /// source. Deliberately kept in memory rather than in `db`: it is a short-lived abuse guard,
+    /// not durable peer state, so it is fine for it to reset on restart.
+    pex_rate_limiter: Mutex<HashMap<PeerId, (SystemTime, u32)>>,
+    /// How each of a peer's multiaddresses was learned about, as recorded by [`Network::add`] /
+    /// [`Network::add_with_source`]. Kept separately from `db` rather than as a `PeerStatus`
+    /// field, same reasoning as `pex_rate_limiter`: it's descriptive bookkeeping the rest of the
+    /// system wasn't tracking before, not part of the durable peer record `hopr_db_api` owns.
+    address_sources: Mutex<HashMap<(PeerId, Multiaddr), AddressSource>>,
+    /// Senders registered by [`Network::subscribe_events`]. A closed receiver's sender is dropped
+    /// the next time [`Network::emit_event`] tries it, so subscribers never need to unsubscribe
+    /// explicitly.
+    event_subscribers: Mutex<Vec<UnboundedSender<NetworkEvent>>>,
 }
 
 impl<T> Network<T>
@@ -125,9 +276,30 @@ where
             db,
             #[cfg(all(feature = "prometheus", not(test)))]
             started_at: current_time().as_unix_timestamp(),
+            pex_rate_limiter: Mutex::new(HashMap::new()),
+            address_sources: Mutex::new(HashMap::new()),
+            event_subscribers: Mutex::new(Vec::new()),
         }
     }
 
+    /// Subscribes to this [`Network`]'s topology changes as a `Stream` of [`NetworkEvent`]s,
+    /// instead of having to repeatedly poll `network_connected_peers()`/`network_peer_info()` and
+    /// diff the results. The stream ends if the `Network` itself is dropped.
+    pub fn subscribe_events(&self) -> impl futures::Stream<Item = NetworkEvent> {
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        self.event_subscribers.lock().unwrap().push(tx);
+        rx
+    }
+
+    /// Fans `event` out to every live subscriber registered via [`Network::subscribe_events`],
+    /// dropping any whose receiver has gone away.
+    fn emit_event(&self, event: NetworkEvent) {
+        self.event_subscribers
+            .lock()
+            .unwrap()
+            .retain(|tx| tx.unbounded_send(event.clone()).is_ok());
+    }
+
     /// Check whether the PeerId is present in the network
     pub async fn has(&self, peer: &PeerId) -> bool {
         peer == &self.me
@@ -137,17 +309,59 @@ where
             })
     }
 
-    /// Add a new peer into the network
+    /// Add a new peer into the network, recording [`AddressSource::inferred`] from `origin` and
+    /// `inbound` for each of `addrs`. See [`Network::add_with_source`] for callers (e.g. mDNS
+    /// discovery) that already know the precise source and don't need it inferred.
     ///
-    /// Each peer must have an origin specification.
-    pub async fn add(&self, peer: &PeerId, origin: PeerOrigin, mut addrs: Vec<Multiaddr>) -> crate::errors::Result<()> {
+    /// Each peer must have an origin specification, and callers must state whether the
+    /// connection was established inbound or outbound so [`Network::reserve_slot`] can keep
+    /// the two pools bounded independently. If any of `addrs` is a relay (`/p2p-circuit`) address,
+    /// this returns an [`NetworkTriggeredEvent::AttemptHolePunch`] the caller should act on.
+    pub async fn add(
+        &self,
+        peer: &PeerId,
+        origin: PeerOrigin,
+        addrs: Vec<Multiaddr>,
+        inbound: bool,
+    ) -> crate::errors::Result<Option<NetworkTriggeredEvent>> {
+        let source = AddressSource::inferred(&origin, inbound);
+        self.add_with_source(peer, origin, addrs, inbound, source).await
+    }
+
+    /// As [`Network::add`], but for callers that already know exactly how each of `addrs` was
+    /// learned about (e.g. mDNS discovery knows it found the address on the LAN, rather than
+    /// leaving [`Network::add`] to infer a source from `origin`).
+    pub async fn add_with_source(
+        &self,
+        peer: &PeerId,
+        origin: PeerOrigin,
+        mut addrs: Vec<Multiaddr>,
+        inbound: bool,
+        source: AddressSource,
+    ) -> crate::errors::Result<Option<NetworkTriggeredEvent>> {
         if peer == &self.me {
             return Err(crate::errors::NetworkingError::DisallowedOperationOnOwnPeerIdError);
         }
 
+        {
+            let mut address_sources = self.address_sources.lock().unwrap();
+            for addr in &addrs {
+                address_sources.insert((*peer, addr.clone()), source);
+            }
+        }
+
+        let relay = relay_hop_address(&addrs);
+        let mut updated_multiaddrs: Option<Vec<Multiaddr>> = None;
+
         if let Some(mut peer_status) = self.db.get_network_peer(peer).await? {
+            if Self::peer_is_banned(&peer_status) {
+                return Err(crate::errors::NetworkingError::PeerIsBannedError);
+            }
+
             if !self.should_still_be_ignored(&peer_status) {
                 peer_status.ignored = None;
+                peer_status.is_inbound = inbound;
+                let addr_count_before = peer_status.multiaddresses.len();
                 peer_status.multiaddresses.append(&mut addrs);
                 peer_status.multiaddresses = peer_status
                     .multiaddresses
@@ -155,6 +369,9 @@ where
                     .collect::<HashSet<_>>()
                     .into_iter()
                     .collect::<Vec<_>>();
+                if peer_status.multiaddresses.len() != addr_count_before {
+                    updated_multiaddrs = Some(peer_status.multiaddresses.clone());
+                }
                 self.db.update_network_peer(peer_status).await?;
             }
         } else {
@@ -167,17 +384,26 @@ where
                     addrs,
                     self.cfg.backoff_exponent,
                     self.cfg.quality_avg_window_size,
+                    inbound,
                 )
                 .await?;
+
+            self.emit_event(NetworkEvent::PeerConnected(*peer));
+        }
+
+        if let Some(multiaddresses) = updated_multiaddrs {
+            self.emit_event(NetworkEvent::MultiaddrsUpdated(*peer, multiaddresses));
         }
 
         #[cfg(all(feature = "prometheus", not(test)))]
         {
             let stats = self.db.network_peer_stats(self.cfg.quality_bad_threshold).await?;
-            self.refresh_metrics(&stats)
+            let has_reachable_priority_peer = self.has_reachable_priority_peer().await?;
+            let latency_percentiles = self.latency_percentiles().await?;
+            self.refresh_metrics(&stats, has_reachable_priority_peer, latency_percentiles)
         }
 
-        Ok(())
+        Ok(relay.map(|relay| NetworkTriggeredEvent::AttemptHolePunch { peer: *peer, relay }))
     }
 
     pub async fn get(&self, peer: &PeerId) -> crate::errors::Result<Option<PeerStatus>> {
@@ -196,6 +422,26 @@ where
         }
     }
 
+    /// How `addr` was learned about for `peer`, as recorded by the most recent [`Network::add`] /
+    /// [`Network::add_with_source`] call that reported it, or `None` if `addr` was never reported
+    /// for `peer` through either of those.
+    pub fn address_source(&self, peer: &PeerId, addr: &Multiaddr) -> Option<AddressSource> {
+        self.address_sources.lock().unwrap().get(&(*peer, addr.clone())).copied()
+    }
+
+    /// `peer`'s rolling RTT estimate (an EWMA over `NetworkConfig::quality_avg_window_size`
+    /// observations), for callers such as the health scorer that want to prefer low-latency peers
+    /// without reaching into the raw [`PeerStatus`].
+    pub async fn rtt_estimate(&self, peer: &PeerId) -> crate::errors::Result<Option<Duration>> {
+        Ok(self.get(peer).await?.map(|status| status.smoothed_latency))
+    }
+
+    /// The direction of `peer`'s currently active connection, as a [`ConnectionDirection`] rather
+    /// than the raw [`PeerStatus::is_inbound`] `bool`.
+    pub async fn connection_direction(&self, peer: &PeerId) -> crate::errors::Result<Option<ConnectionDirection>> {
+        Ok(self.get(peer).await?.map(|status| status.is_inbound.into()))
+    }
+
     /// Remove peer from the network
     pub async fn remove(&self, peer: &PeerId) -> crate::errors::Result<()> {
         if peer == &self.me {
@@ -203,17 +449,46 @@ where
         }
 
         self.db.remove_network_peer(peer).await?;
+        self.emit_event(NetworkEvent::PeerDisconnected(*peer));
 
         #[cfg(all(feature = "prometheus", not(test)))]
         {
             let stats = self.db.network_peer_stats(self.cfg.quality_bad_threshold).await?;
-            self.refresh_metrics(&stats)
+            let has_reachable_priority_peer = self.has_reachable_priority_peer().await?;
+            let latency_percentiles = self.latency_percentiles().await?;
+            self.refresh_metrics(&stats, has_reachable_priority_peer, latency_percentiles)
         }
 
         Ok(())
     }
 
-    /// Update the peer record with the observation
+    /// Scans all known peers for ones whose `last_seen` is at or before `last_seen_before`,
+    /// emits [`NetworkEvent::PeerExpired`] for each, then prunes it via [`Network::remove`] so it
+    /// does not silently linger in the results of `network_connected_peers()`. Modeled on the
+    /// "ExpirePeer" pattern used by other Rust p2p stacks; meant to be driven by a background
+    /// sweeper that periodically calls this with `now - ttl`.
+    pub async fn sweep_expired_peers(&self, last_seen_before: SystemTime) -> crate::errors::Result<Vec<PeerId>> {
+        let stream = self
+            .db
+            .get_network_peers(PeerSelector::default().with_last_seen_lte(last_seen_before), false)
+            .await?;
+        futures::pin_mut!(stream);
+        let expired: Vec<PeerId> = stream.map(|p| p.id.1).collect().await;
+
+        for peer in &expired {
+            self.emit_event(NetworkEvent::PeerExpired(*peer));
+            self.remove(peer).await?;
+        }
+
+        Ok(expired)
+    }
+
+    /// Update the peer record with the observation.
+    ///
+    /// Besides the existing quality bookkeeping, every observation also moves the peer's
+    /// signed reputation score by the configured per-event delta and lets it decay back
+    /// towards zero, so a peer that repeatedly fails pings is disconnected once its
+    /// reputation drops to or below [`NetworkConfig::reputation_banned_threshold`].
     pub async fn update(
         &self,
         peer: &PeerId,
@@ -232,35 +507,126 @@ where
             entry.heartbeats_sent += 1;
             entry.peer_version = version;
 
+            // Decay the reputation back towards zero on every observation, so a past streak of
+            // good or bad pings does not stick around forever once the peer's behavior changes,
+            // mirroring the periodic decay of Substrate's peerset reputation.
+            entry.reputation -= entry.reputation / self.cfg.reputation_decay_divisor;
+
             if let Ok(latency) = ping_result {
                 entry.last_seen = current_time();
                 entry.last_seen_latency = latency;
+
+                // EWMA over the same window the node already uses to average quality, so
+                // `smoothed_latency` tracks the peer's typical RTT rather than its last one.
+                entry.smoothed_latency = if entry.smoothed_latency.is_zero() {
+                    latency
+                } else {
+                    let alpha = 2.0 / (self.cfg.quality_avg_window_size as f64 + 1.0);
+                    Duration::from_secs_f64(
+                        alpha * latency.as_secs_f64() + (1.0 - alpha) * entry.smoothed_latency.as_secs_f64(),
+                    )
+                };
+
                 entry.heartbeats_succeeded += 1;
                 entry.backoff = self.cfg.backoff_min;
-                entry.update_quality(1.0_f64.min(entry.get_quality() + self.cfg.quality_step));
+
+                // A successful contact means the peer is reachable again, so the reconnection
+                // backoff built up by consecutive failures no longer applies.
+                entry.reconnect_delay = self.cfg.reconnect_base_delay;
+                entry.reconnect_attempts = 0;
+                entry.last_failure_at = None;
+
+                // A reachable but sluggish peer is still reachable, but a ping slower than
+                // `latency_penalty_threshold` earns it no quality or reputation reward at all.
+                let reward_scale = if latency > self.cfg.latency_penalty_threshold {
+                    0.0
+                } else {
+                    1.0
+                };
+
+                entry.reputation = entry
+                    .reputation
+                    .saturating_add((self.cfg.reputation_reward as f64 * reward_scale) as i32);
+                entry.update_quality(1.0_f64.min(entry.get_quality() + self.cfg.quality_step * reward_scale));
+
+                // A separate EWMA of how good the observed latency itself was, alongside (not
+                // instead of) `quality`. Unlike `quality`, which only tracks reachability, this
+                // lets routing prefer the fastest among several peers that are all already above
+                // the offline threshold.
+                let latency_sample = if self.cfg.quality_max_good_latency.is_zero() {
+                    0.0
+                } else {
+                    (1.0 - latency.as_secs_f64() / self.cfg.quality_max_good_latency.as_secs_f64()).clamp(0.0, 1.0)
+                };
+                entry.latency_score =
+                    self.cfg.quality_alpha * latency_sample + (1.0 - self.cfg.quality_alpha) * entry.latency_score;
             } else {
                 entry.backoff = self.cfg.backoff_max.max(entry.backoff.powf(self.cfg.backoff_exponent));
+                entry.reputation = entry.reputation.saturating_sub(self.cfg.reputation_penalty);
                 entry.update_quality(0.0_f64.max(entry.get_quality() - self.cfg.quality_step));
 
-                if entry.get_quality() < (self.cfg.quality_step / 2.0) {
-                    return Ok(Some(NetworkTriggeredEvent::CloseConnection(entry.id.1)));
-                } else if entry.get_quality() < self.cfg.quality_bad_threshold {
-                    entry.ignored = Some(current_time());
+                // Grow the reconnection delay geometrically on each consecutive failure, capped
+                // at `reconnect_max_delay`, so repeated failures back off rather than hammering a
+                // peer that is probably down.
+                entry.reconnect_delay = if entry.reconnect_delay.is_zero() {
+                    self.cfg.reconnect_base_delay
+                } else {
+                    std::cmp::min(
+                        Duration::from_secs_f64(entry.reconnect_delay.as_secs_f64() * self.cfg.reconnect_backoff_factor),
+                        self.cfg.reconnect_max_delay,
+                    )
+                };
+                entry.reconnect_attempts = entry.reconnect_attempts.saturating_add(1);
+                entry.last_failure_at = Some(current_time());
+
+                // A failed ping is the worst possible latency sample.
+                entry.latency_score = (1.0 - self.cfg.quality_alpha) * entry.latency_score;
+
+                if !entry.is_priority {
+                    if entry.reputation <= self.cfg.reputation_banned_threshold {
+                        // Ban longer the further the peer overshot the threshold, so a peer that
+                        // free-falls through a single burst of misbehavior is kept out longer than
+                        // one that merely grazed it.
+                        let overshoot = (self.cfg.reputation_banned_threshold - entry.reputation).max(1) as u32;
+                        let banned_until = current_time() + self.cfg.reputation_ban_duration_base * overshoot;
+                        entry.banned_until = Some(banned_until);
+                        self.db.update_network_peer(entry.clone()).await?;
+                        return Ok(Some(NetworkTriggeredEvent::Ban(entry.id.1, banned_until)));
+                    } else if entry.get_quality() < self.cfg.quality_bad_threshold {
+                        entry.ignored = Some(current_time());
+                    }
                 }
             }
 
+            // A successful ping to a peer we can currently only reach via a relay is exactly the
+            // DCUtR/simultaneous-open moment: both sides are known to be up, so it's worth
+            // coordinating a direct hole-punch attempt. Reuse the backoff fields so repeated
+            // failures to connect directly retry with the same exponential spacing as pings,
+            // instead of hammering the relay on every heartbeat.
+            let hole_punch = ping_result
+                .is_ok()
+                .then(|| relay_hop_address(&entry.multiaddresses))
+                .flatten()
+                .filter(|_| self.hole_punch_due(&entry))
+                .map(|relay| {
+                    entry.hole_punch_attempted_at = Some(current_time());
+                    NetworkTriggeredEvent::AttemptHolePunch { peer: entry.id.1, relay }
+                });
+
             self.db.update_network_peer(entry.clone()).await?;
 
             #[cfg(all(feature = "prometheus", not(test)))]
             {
                 let stats = self.db.network_peer_stats(self.cfg.quality_bad_threshold).await?;
-                self.refresh_metrics(&stats)
+                let has_reachable_priority_peer = self.has_reachable_priority_peer().await?;
+                let latency_percentiles = self.latency_percentiles().await?;
+                self.refresh_metrics(&stats, has_reachable_priority_peer, latency_percentiles)
             }
 
-            Ok(Some(NetworkTriggeredEvent::UpdateQuality(
+            Ok(Some(hole_punch.unwrap_or(NetworkTriggeredEvent::UpdateQuality(
                 entry.id.1,
                 entry.get_quality(),
-            )))
+            ))))
         } else {
             debug!("Ignoring update request for unknown peer {}", peer);
             Ok(None)
@@ -269,17 +635,18 @@ where
 
     /// Returns the quality of the network as a network health indicator.
     pub async fn health(&self) -> Health {
-        self.db
-            .network_peer_stats(self.cfg.quality_bad_threshold)
-            .await
-            .map(|stats| health_from_stats(&stats, self.am_i_public))
-            .unwrap_or(Health::Unknown)
+        let Ok(stats) = self.db.network_peer_stats(self.cfg.quality_bad_threshold).await else {
+            return Health::Unknown;
+        };
+        let has_reachable_priority_peer = self.has_reachable_priority_peer().await.unwrap_or(false);
+
+        health_from_stats(&stats, self.am_i_public, has_reachable_priority_peer)
     }
 
     /// Update the internally perceived network status that is processed to the network health
     #[cfg(all(feature = "prometheus", not(test)))]
-    fn refresh_metrics(&self, stats: &Stats) {
-        let health = health_from_stats(stats, self.am_i_public);
+    fn refresh_metrics(&self, stats: &Stats, has_reachable_priority_peer: bool, latency_percentiles: (Duration, Duration)) {
+        let health = health_from_stats(stats, self.am_i_public, has_reachable_priority_peer);
 
         if METRIC_NETWORK_HEALTH_TIME_TO_GREEN.get() < 0.5f64 {
             if let Some(ts) = current_time().checked_sub(self.started_at) {
@@ -292,6 +659,9 @@ where
         METRIC_PEERS_BY_QUALITY.set(&["nonPublic", "high"], stats.good_quality_non_public as f64);
         METRIC_PEERS_BY_QUALITY.set(&["nonPublic", "low"], stats.bad_quality_non_public as f64);
         METRIC_NETWORK_HEALTH.set((health as i32).into());
+        let (p50, p95) = latency_percentiles;
+        METRIC_PEER_LATENCY_P50.set(p50.as_secs_f64() * 1000.0);
+        METRIC_PEER_LATENCY_P95.set(p95.as_secs_f64() * 1000.0);
     }
 
     // ======
@@ -316,8 +686,12 @@ where
                 if v.id.1 == self.me {
                     return None;
                 }
-                let backoff = v.backoff.powf(self.cfg.backoff_exponent);
-                let delay = std::cmp::min(self.cfg.min_delay * (backoff as u32), self.cfg.max_delay);
+                let delay = if v.is_priority {
+                    self.cfg.priority_ping_interval
+                } else {
+                    let backoff = v.backoff.powf(self.cfg.backoff_exponent);
+                    std::cmp::min(self.cfg.min_delay * (backoff as u32), self.cfg.max_delay)
+                };
 
                 if (v.last_seen + delay) < threshold {
                     Some(v)
@@ -339,20 +713,329 @@ where
         Ok(data.into_iter().map(|peer| peer.id.1).collect())
     }
 
+    /// The next time a reconnection attempt to `peer` is due, or `None` if it has never failed a
+    /// ping (and so has no outstanding backoff).
+    pub async fn next_reconnect_at(&self, peer: &PeerId) -> crate::errors::Result<Option<SystemTime>> {
+        Ok(self.db.get_network_peer(peer).await?.and_then(|p| {
+            p.last_failure_at
+                .map(|failed_at| failed_at + jittered_delay(&p.id.1, p.reconnect_attempts, p.reconnect_delay))
+        }))
+    }
+
+    /// Returns an [`NetworkTriggeredEvent::AttemptReconnect`] for every peer whose reconnection
+    /// backoff (see [`Network::update`] and [`Network::next_reconnect_at`]) has elapsed as of
+    /// `now`, for the periodic maintenance loop to act on.
+    pub async fn find_peers_to_reconnect(&self, now: SystemTime) -> crate::errors::Result<Vec<NetworkTriggeredEvent>> {
+        let stream = self.db.get_network_peers(Default::default(), false).await?;
+        futures::pin_mut!(stream);
+
+        let due: Vec<PeerId> = stream
+            .filter_map(|v| async move {
+                let failed_at = v.last_failure_at?;
+                let due_at = failed_at + jittered_delay(&v.id.1, v.reconnect_attempts, v.reconnect_delay);
+                (due_at <= now).then_some(v.id.1)
+            })
+            .collect()
+            .await;
+
+        Ok(due.into_iter().map(NetworkTriggeredEvent::AttemptReconnect).collect())
+    }
+
+    /// Decide whether a new connection to `peer` in the given direction may proceed, bounding
+    /// the number of concurrently connected inbound/outbound peers the way sc-peerset's slot
+    /// allocator does.
+    ///
+    /// If a free slot exists, the candidate is accepted outright. Otherwise, the
+    /// lowest-quality currently-connected peer of the same direction is picked for eviction and
+    /// returned alongside [`SlotDecision::Accept`] so the caller can close that connection and
+    /// free the slot. Peers added via [`PeerOrigin::Initialization`] (e.g. the node's own
+    /// bootstrap/priority peers) are never picked for eviction; if none of the occupants are
+    /// eligible, the request is [`SlotDecision::Reject`]ed.
+    ///
+    /// This is a pure query: it computes a decision but does not itself mark the slot as
+    /// provisionally taken. Callers must serialize calls to `reserve_slot` for the same
+    /// direction (e.g. behind a single dial-handling task), since two concurrent calls against
+    /// the last open slot, or against the same lowest-quality eviction victim, can otherwise both
+    /// legitimately return `Accept` and transiently exceed `max_inbound_peers`/`max_outbound_peers`
+    /// before either caller has finished acting on its decision.
+    pub async fn reserve_slot(&self, peer: &PeerId, inbound: bool) -> crate::errors::Result<SlotDecision> {
+        let max_slots = if inbound {
+            self.cfg.max_inbound_peers
+        } else {
+            self.cfg.max_outbound_peers
+        };
+
+        let candidate = *peer;
+        let mut occupants = self
+            .peer_filter(move |p| async move { (p.id.1 != candidate && p.is_inbound == inbound).then_some(p) })
+            .await?;
+
+        if (occupants.len() as u32) < max_slots {
+            return Ok(SlotDecision::Accept(None));
+        }
+
+        occupants.retain(|p| p.origin != PeerOrigin::Initialization);
+
+        if inbound {
+            Self::protect_inbound_occupants(&mut occupants, &self.cfg);
+        }
+
+        occupants.sort_by(|a, b| {
+            a.get_quality()
+                .partial_cmp(&b.get_quality())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        match occupants.first() {
+            Some(victim) => Ok(SlotDecision::Accept(Some(NetworkTriggeredEvent::CloseConnection(
+                victim.id.1,
+            )))),
+            None => Ok(SlotDecision::Reject),
+        }
+    }
+
+    /// Removes from `occupants` the inbound peers protected from eviction: the
+    /// `inbound_protected_recent` most recently connected, the `inbound_protected_fastest` with
+    /// the lowest observed latency, and up to one peer per distinct `inbound_protected_prefixes`
+    /// address prefix (to preserve network diversity). This keeps an attacker from monopolizing
+    /// every inbound slot with many low-quality connections that otherwise all look equally
+    /// evictable. Sorting by peer id before bucketing keeps the result deterministic given the
+    /// same peer set.
+    fn protect_inbound_occupants(occupants: &mut Vec<PeerStatus>, cfg: &NetworkConfig) {
+        occupants.sort_by_key(|p| p.id.1.to_bytes());
+
+        let mut protected: HashSet<PeerId> = HashSet::new();
+
+        let mut by_recency = occupants.clone();
+        by_recency.sort_by(|a, b| b.last_seen.cmp(&a.last_seen));
+        protected.extend(
+            by_recency
+                .into_iter()
+                .take(cfg.inbound_protected_recent as usize)
+                .map(|p| p.id.1),
+        );
+
+        let mut by_latency = occupants.clone();
+        by_latency.sort_by(|a, b| a.last_seen_latency.cmp(&b.last_seen_latency));
+        protected.extend(
+            by_latency
+                .into_iter()
+                .take(cfg.inbound_protected_fastest as usize)
+                .map(|p| p.id.1),
+        );
+
+        let mut seen_prefixes: HashSet<String> = HashSet::new();
+        for p in occupants.iter() {
+            if seen_prefixes.len() as u32 >= cfg.inbound_protected_prefixes {
+                break;
+            }
+            if let Some(prefix) = address_prefix(&p.multiaddresses) {
+                if seen_prefixes.insert(prefix) {
+                    protected.insert(p.id.1);
+                }
+            }
+        }
+
+        occupants.retain(|p| !protected.contains(&p.id.1));
+    }
+
     pub(crate) fn should_still_be_ignored(&self, peer: &PeerStatus) -> bool {
+        if peer.is_priority {
+            return false;
+        }
+
         peer.ignored
             .map(|t| current_time().saturating_sub(t) < self.cfg.ignore_timeframe)
             .unwrap_or(false)
     }
+
+    /// Mark `peer` as a high-priority (TIER1-style) peer, or unmark it.
+    ///
+    /// Priority peers are exempt from the ignore-timeframe and reputation-banning logic in
+    /// [`Network::should_still_be_ignored`] and [`Network::update`], and are always scheduled
+    /// for pinging on a short, fixed interval regardless of their current backoff.
+    pub async fn set_priority(&self, peer: &PeerId, priority: bool) -> crate::errors::Result<()> {
+        if let Some(mut entry) = self.db.get_network_peer(peer).await? {
+            entry.is_priority = priority;
+            self.db.update_network_peer(entry).await?;
+        }
+
+        Ok(())
+    }
+
+    /// The peer's current signed reputation score, or `None` if it is not (or no longer) known.
+    pub async fn reputation(&self, peer: &PeerId) -> crate::errors::Result<Option<i32>> {
+        Ok(self.db.get_network_peer(peer).await?.map(|p| p.reputation))
+    }
+
+    /// An EWMA of how good `peer`'s observed ping latency has been, in `[0, 1]`, smoothed by
+    /// [`NetworkConfig::quality_alpha`] against [`NetworkConfig::quality_max_good_latency`].
+    /// Unlike [`PeerStatus::get_quality`], which only reflects reachability, this lets routing
+    /// prefer the fastest among several peers that are all already above the offline threshold.
+    pub async fn latency_weighted_quality(&self, peer: &PeerId) -> crate::errors::Result<Option<f64>> {
+        Ok(self.db.get_network_peer(peer).await?.map(|p| p.latency_score))
+    }
+
+    /// Whether `peer` is currently serving out a reputation-driven ban and must not be re-admitted.
+    pub async fn is_banned(&self, peer: &PeerId) -> crate::errors::Result<bool> {
+        Ok(self
+            .db
+            .get_network_peer(peer)
+            .await?
+            .is_some_and(|p| Self::peer_is_banned(&p)))
+    }
+
+    fn peer_is_banned(peer: &PeerStatus) -> bool {
+        peer.banned_until.is_some_and(|t| t > current_time())
+    }
+
+    /// Whether `peer`'s quality has fallen below [`NetworkConfig::client_mode_quality_threshold`].
+    /// A client-mode peer stays in the table and remains eligible for outbound connections, but
+    /// is excluded from [`Network::sample_shareable_peers`]. Relay-hop selection does not consult
+    /// this classification, so a client-mode peer can still be picked as a relay hop.
+    pub async fn is_client_mode(&self, peer: &PeerId) -> crate::errors::Result<Option<bool>> {
+        let threshold = self.cfg.client_mode_quality_threshold;
+        Ok(self
+            .db
+            .get_network_peer(peer)
+            .await?
+            .map(|p| Self::is_client_mode_status(&p, threshold)))
+    }
+
+    fn is_client_mode_status(peer: &PeerStatus, threshold: f64) -> bool {
+        peer.get_quality() < threshold
+    }
+
+    /// Samples up to `limit` of this node's known peers that are above
+    /// [`NetworkConfig::pex_quality_threshold`], have proven themselves via at least one
+    /// successful ping, and are not currently banned or in [`Network::is_client_mode`], best
+    /// quality first. Used to answer a PEX `GetPeers` request without handing a requester a list
+    /// of merely-claimed, unverified, flaky, or banned peers.
+    pub async fn sample_shareable_peers(&self, limit: usize) -> crate::errors::Result<Vec<PeerStatus>> {
+        let quality_threshold = self.cfg.pex_quality_threshold;
+        let client_mode_threshold = self.cfg.client_mode_quality_threshold;
+        let mut candidates = self
+            .peer_filter(move |p| async move {
+                (p.heartbeats_succeeded > 0
+                    && p.get_quality() >= quality_threshold
+                    && !Self::is_client_mode_status(&p, client_mode_threshold)
+                    && !Self::peer_is_banned(&p))
+                    .then_some(p)
+            })
+            .await?;
+
+        candidates.sort_by(|a, b| {
+            b.get_quality()
+                .partial_cmp(&a.get_quality())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        candidates.truncate(limit);
+
+        Ok(candidates)
+    }
+
+    /// Ingests peers learned from a PEX exchange with `source`, adding any not already known via
+    /// [`Network::add`] at the usual conservative starting quality so they must prove themselves
+    /// via pings before being used for routing. Returns how many were actually ingested.
+    ///
+    /// `source` is rate-limited to [`NetworkConfig::pex_max_peers_per_source_per_interval`]
+    /// contributions per [`NetworkConfig::pex_rate_limit_interval`], so a single malicious or
+    /// buggy peer cannot flood the peer table.
+    pub async fn ingest_exchanged_peers(
+        &self,
+        source: &PeerId,
+        peers: Vec<(PeerId, Vec<Multiaddr>)>,
+    ) -> crate::errors::Result<usize> {
+        let allowance = self.take_pex_allowance(source, peers.len() as u32);
+
+        let mut ingested = 0;
+        for (peer, addrs) in peers.into_iter().take(allowance as usize) {
+            if peer == self.me || peer == *source {
+                continue;
+            }
+
+            if self
+                .add(&peer, PeerOrigin::PeerExchange(*source), addrs, false)
+                .await
+                .is_ok()
+            {
+                ingested += 1;
+            }
+        }
+
+        Ok(ingested)
+    }
+
+    /// How many of `requested` new peers `source` may still contribute this rate-limit interval,
+    /// resetting the window first if it has elapsed.
+    fn take_pex_allowance(&self, source: &PeerId, requested: u32) -> u32 {
+        let mut limiter = self.pex_rate_limiter.lock().unwrap_or_else(|e| e.into_inner());
+        let now = current_time();
+        let window = limiter.entry(*source).or_insert((now, 0));
+
+        if now.saturating_sub(window.0) >= self.cfg.pex_rate_limit_interval {
+            *window = (now, 0);
+        }
+
+        let remaining = self.cfg.pex_max_peers_per_source_per_interval.saturating_sub(window.1);
+        let granted = remaining.min(requested);
+        window.1 += granted;
+
+        granted
+    }
+
+    /// Whether enough time has passed since the last hole-punch attempt towards `peer` to try
+    /// again, using the same exponential-backoff spacing as [`Network::find_peers_to_ping`].
+    fn hole_punch_due(&self, peer: &PeerStatus) -> bool {
+        let Some(attempted_at) = peer.hole_punch_attempted_at else {
+            return true;
+        };
+
+        let backoff = peer.backoff.powf(self.cfg.backoff_exponent);
+        let delay = std::cmp::min(self.cfg.min_delay * (backoff as u32), self.cfg.max_delay);
+
+        current_time().saturating_sub(attempted_at) >= delay
+    }
+
+    async fn has_reachable_priority_peer(&self) -> crate::errors::Result<bool> {
+        let bad_quality_threshold = self.cfg.quality_bad_threshold;
+        Ok(!self
+            .peer_filter(move |p| async move {
+                (p.is_priority && p.get_quality() >= bad_quality_threshold).then_some(())
+            })
+            .await?
+            .is_empty())
+    }
+
+    /// The p50 and p95 smoothed RTT across all known peers, reported through the
+    /// `hopr_peer_latency_p50_ms` and `hopr_peer_latency_p95_ms` metrics.
+    #[cfg(all(feature = "prometheus", not(test)))]
+    async fn latency_percentiles(&self) -> crate::errors::Result<(Duration, Duration)> {
+        let mut latencies = self
+            .peer_filter(|p| async move { (!p.smoothed_latency.is_zero()).then_some(p.smoothed_latency) })
+            .await?;
+        latencies.sort();
+
+        let percentile = |p: f64| -> Duration {
+            if latencies.is_empty() {
+                return Duration::ZERO;
+            }
+            let index = ((latencies.len() - 1) as f64 * p).round() as usize;
+            latencies[index]
+        };
+
+        Ok((percentile(0.5), percentile(0.95)))
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::network::{Health, Network, NetworkConfig, NetworkTriggeredEvent, PeerOrigin};
+    use crate::network::{Health, Network, NetworkConfig, NetworkTriggeredEvent, PeerOrigin, SlotDecision};
     use hopr_crypto_types::keypairs::{ChainKeypair, Keypair, OffchainKeypair};
     use hopr_platform::time::native::current_time;
     use hopr_primitive_types::prelude::AsUnixTimestamp;
     use libp2p_identity::PeerId;
+    use multiaddr::{Multiaddr, Protocol};
     use std::ops::Add;
     use std::time::Duration;
 
@@ -370,6 +1053,9 @@ mod tests {
     async fn basic_network(my_id: &PeerId) -> Network<hopr_db_sql::db::HoprDb> {
         let mut cfg = NetworkConfig::default();
         cfg.quality_offline_threshold = 0.6;
+        // Keep reputation-based banning out of the way for tests that only exercise the
+        // quality/backoff behavior; banning itself is covered by its own dedicated tests.
+        cfg.reputation_banned_threshold = i32::MIN;
         Network::new(
             *my_id,
             vec![],
@@ -393,7 +1079,7 @@ mod tests {
 
         let peers = basic_network(&me).await;
 
-        assert!(peers.add(&me, PeerOrigin::IncomingConnection, vec![]).await.is_err());
+        assert!(peers.add(&me, PeerOrigin::IncomingConnection, vec![], true).await.is_err());
 
         assert_eq!(
             0,
@@ -414,7 +1100,7 @@ mod tests {
         let peers = basic_network(&me).await;
 
         peers
-            .add(&expected, PeerOrigin::IncomingConnection, vec![])
+            .add(&expected, PeerOrigin::IncomingConnection, vec![], true)
             .await
             .unwrap();
 
@@ -436,7 +1122,7 @@ mod tests {
 
         let peers = basic_network(&me).await;
 
-        peers.add(&peer, PeerOrigin::IncomingConnection, vec![]).await.unwrap();
+        peers.add(&peer, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
 
         peers.remove(&peer).await.expect("should not fail on DB remove");
 
@@ -481,7 +1167,7 @@ mod tests {
 
         let peers = basic_network(&me).await;
 
-        peers.add(&peer, PeerOrigin::IncomingConnection, vec![]).await.unwrap();
+        peers.add(&peer, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
 
         let latency = 123u64;
 
@@ -508,7 +1194,7 @@ mod tests {
 
         {
             peers
-                .add(&peer, PeerOrigin::IncomingConnection, vec![])
+                .add(&peer, PeerOrigin::IncomingConnection, vec![], true)
                 .await
                 .expect("should not fail on DB add");
             peers
@@ -548,7 +1234,7 @@ mod tests {
 
         let peers = basic_network(&me).await;
 
-        peers.add(&peer, PeerOrigin::IncomingConnection, vec![]).await.unwrap();
+        peers.add(&peer, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
 
         peers
             .update(&peer, Ok(current_time().as_unix_timestamp()), None)
@@ -565,58 +1251,389 @@ mod tests {
         assert!(!peers.has(&peer).await);
 
         // peer should remain ignored and not be added
-        peers.add(&peer, PeerOrigin::IncomingConnection, vec![]).await.unwrap();
+        peers.add(&peer, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
 
         assert!(!peers.has(&peer).await)
     }
 
     #[async_std::test]
-    async fn test_network_should_be_able_to_register_a_failed_heartbeat_result() {
+    async fn test_network_reserve_slot_should_accept_when_a_free_slot_is_available() {
         let peer: PeerId = OffchainKeypair::random().public().into();
         let me: PeerId = OffchainKeypair::random().public().into();
 
         let peers = basic_network(&me).await;
 
-        peers.add(&peer, PeerOrigin::IncomingConnection, vec![]).await.unwrap();
+        assert_eq!(
+            peers.reserve_slot(&peer, true).await.unwrap(),
+            SlotDecision::Accept(None)
+        );
+    }
+
+    #[async_std::test]
+    async fn test_network_reserve_slot_should_evict_the_lowest_quality_peer_of_the_same_direction_when_full() {
+        let low_quality: PeerId = OffchainKeypair::random().public().into();
+        let high_quality: PeerId = OffchainKeypair::random().public().into();
+        let candidate: PeerId = OffchainKeypair::random().public().into();
+        let me: PeerId = OffchainKeypair::random().public().into();
 
-        // Needs to do 3 pings, so we get over the ignore threshold limit
-        // when doing the 4th failed ping
-        peers
-            .update(&peer, Ok(std::time::Duration::from_millis(123_u64)), None)
-            .await
-            .expect("no error should occur");
+        let mut cfg = NetworkConfig::default();
+        cfg.quality_offline_threshold = 0.6;
+        cfg.reputation_banned_threshold = i32::MIN;
+        cfg.max_inbound_peers = 2;
+        cfg.inbound_protected_recent = 0;
+        cfg.inbound_protected_fastest = 0;
+        cfg.inbound_protected_prefixes = 0;
+
+        let peers = Network::new(
+            me,
+            vec![],
+            cfg,
+            hopr_db_sql::db::HoprDb::new_in_memory(ChainKeypair::random()).await,
+        );
+
+        peers.add(&low_quality, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
         peers
-            .update(&peer, Ok(std::time::Duration::from_millis(200_u64)), None)
+            .add(&high_quality, PeerOrigin::IncomingConnection, vec![], true)
             .await
-            .expect("no error should occur");
+            .unwrap();
+
         peers
-            .update(&peer, Ok(std::time::Duration::from_millis(200_u64)), None)
+            .update(&high_quality, Ok(std::time::Duration::from_millis(10u64)), None)
             .await
             .expect("no error should occur");
 
-        peers.update(&peer, Err(()), None).await.expect("no error should occur");
+        assert_eq!(
+            peers.reserve_slot(&candidate, true).await.unwrap(),
+            SlotDecision::Accept(Some(NetworkTriggeredEvent::CloseConnection(low_quality)))
+        );
+    }
 
-        let actual = peers
-            .get(&peer)
-            .await
-            .unwrap()
-            .expect("the peer record should be present");
+    #[async_std::test]
+    async fn test_network_reserve_slot_should_reject_when_all_occupants_are_exempt_from_eviction() {
+        let me: PeerId = OffchainKeypair::random().public().into();
+        let priority: PeerId = OffchainKeypair::random().public().into();
+        let candidate: PeerId = OffchainKeypair::random().public().into();
 
-        assert_eq!(actual.heartbeats_succeeded, 3);
-        assert_eq!(actual.backoff, 300f64);
+        let mut cfg = NetworkConfig::default();
+        cfg.quality_offline_threshold = 0.6;
+        cfg.reputation_banned_threshold = i32::MIN;
+        cfg.max_inbound_peers = 1;
+        cfg.inbound_protected_recent = 0;
+        cfg.inbound_protected_fastest = 0;
+        cfg.inbound_protected_prefixes = 0;
+
+        let peers = Network::new(
+            me,
+            vec![],
+            cfg,
+            hopr_db_sql::db::HoprDb::new_in_memory(ChainKeypair::random()).await,
+        );
+
+        peers.add(&priority, PeerOrigin::Initialization, vec![], true).await.unwrap();
+
+        assert_eq!(peers.reserve_slot(&candidate, true).await.unwrap(), SlotDecision::Reject);
     }
 
     #[async_std::test]
-    async fn test_network_peer_should_be_listed_for_the_ping_if_last_recorded_later_than_reference() {
-        let first: PeerId = OffchainKeypair::random().public().into();
-        let second: PeerId = OffchainKeypair::random().public().into();
+    async fn test_network_reserve_slot_should_not_evict_a_recently_connected_protected_peer() {
+        let recent: PeerId = OffchainKeypair::random().public().into();
+        let stale: PeerId = OffchainKeypair::random().public().into();
+        let candidate: PeerId = OffchainKeypair::random().public().into();
         let me: PeerId = OffchainKeypair::random().public().into();
 
-        let peers = basic_network(&me).await;
+        let mut cfg = NetworkConfig::default();
+        cfg.quality_offline_threshold = 0.6;
+        cfg.reputation_banned_threshold = i32::MIN;
+        cfg.max_inbound_peers = 2;
+        cfg.inbound_protected_recent = 1;
+        cfg.inbound_protected_fastest = 0;
+        cfg.inbound_protected_prefixes = 0;
+
+        let peers = Network::new(
+            me,
+            vec![],
+            cfg,
+            hopr_db_sql::db::HoprDb::new_in_memory(ChainKeypair::random()).await,
+        );
+
+        peers.add(&stale, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
+        peers.add(&recent, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
 
-        peers.add(&first, PeerOrigin::IncomingConnection, vec![]).await.unwrap();
+        // both peers are otherwise equally (un-pinged) quality, but only `recent` gets a fresh
+        // `last_seen` from a successful ping
         peers
-            .add(&second, PeerOrigin::IncomingConnection, vec![])
+            .update(&recent, Ok(std::time::Duration::from_millis(10u64)), None)
+            .await
+            .expect("no error should occur");
+
+        assert_eq!(
+            peers.reserve_slot(&candidate, true).await.unwrap(),
+            SlotDecision::Accept(Some(NetworkTriggeredEvent::CloseConnection(stale)))
+        );
+    }
+
+    #[async_std::test]
+    async fn test_network_reserve_slot_should_not_evict_the_fastest_protected_peer() {
+        let fast: PeerId = OffchainKeypair::random().public().into();
+        let slow: PeerId = OffchainKeypair::random().public().into();
+        let candidate: PeerId = OffchainKeypair::random().public().into();
+        let me: PeerId = OffchainKeypair::random().public().into();
+
+        let mut cfg = NetworkConfig::default();
+        cfg.quality_offline_threshold = 0.6;
+        cfg.reputation_banned_threshold = i32::MIN;
+        cfg.max_inbound_peers = 2;
+        cfg.inbound_protected_recent = 0;
+        cfg.inbound_protected_fastest = 1;
+        cfg.inbound_protected_prefixes = 0;
+
+        let peers = Network::new(
+            me,
+            vec![],
+            cfg,
+            hopr_db_sql::db::HoprDb::new_in_memory(ChainKeypair::random()).await,
+        );
+
+        peers.add(&fast, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
+        peers.add(&slow, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
+
+        peers
+            .update(&fast, Ok(std::time::Duration::from_millis(5u64)), None)
+            .await
+            .expect("no error should occur");
+        peers
+            .update(&slow, Ok(std::time::Duration::from_millis(500u64)), None)
+            .await
+            .expect("no error should occur");
+
+        assert_eq!(
+            peers.reserve_slot(&candidate, true).await.unwrap(),
+            SlotDecision::Accept(Some(NetworkTriggeredEvent::CloseConnection(slow)))
+        );
+    }
+
+    #[async_std::test]
+    async fn test_network_should_not_close_connection_to_a_priority_peer_on_failed_pings() {
+        let peer: PeerId = OffchainKeypair::random().public().into();
+        let me: PeerId = OffchainKeypair::random().public().into();
+
+        let mut cfg = NetworkConfig::default();
+        cfg.quality_offline_threshold = 0.6;
+        cfg.reputation_banned_threshold = -1;
+
+        let peers = Network::new(
+            me,
+            vec![],
+            cfg,
+            hopr_db_sql::db::HoprDb::new_in_memory(ChainKeypair::random()).await,
+        );
+
+        peers.add(&peer, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
+        peers.set_priority(&peer, true).await.expect("should not fail on DB update");
+
+        assert_eq!(
+            peers.update(&peer, Err(()), None).await.expect("no error should occur"),
+            Some(NetworkTriggeredEvent::UpdateQuality(peer, 0.0))
+        );
+        assert!(peers.has(&peer).await);
+    }
+
+    #[async_std::test]
+    async fn test_network_should_never_ignore_a_priority_peer() {
+        let peer: PeerId = OffchainKeypair::random().public().into();
+        let me: PeerId = OffchainKeypair::random().public().into();
+
+        let peers = basic_network(&me).await;
+
+        peers.add(&peer, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
+        peers.set_priority(&peer, true).await.expect("should not fail on DB update");
+
+        peers.update(&peer, Err(()), None).await.expect("no error should occur");
+
+        assert!(peers.has(&peer).await);
+    }
+
+    #[async_std::test]
+    async fn test_network_should_be_at_least_yellow_with_a_reachable_priority_peer() {
+        let peer: PeerId = OffchainKeypair::random().public().into();
+        let me: PeerId = OffchainKeypair::random().public().into();
+
+        let peers = basic_network(&me).await;
+
+        peers.add(&peer, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
+        peers.set_priority(&peer, true).await.expect("should not fail on DB update");
+
+        peers
+            .update(&peer, Ok(current_time().as_unix_timestamp()), None)
+            .await
+            .expect("no error should occur");
+
+        // a reachable priority peer should guarantee at least Yellow, even without a public relay
+        assert!(peers.health().await >= Health::Yellow);
+    }
+
+    #[async_std::test]
+    async fn test_network_add_should_suggest_a_hole_punch_for_a_peer_known_only_via_a_relay() {
+        let peer: PeerId = OffchainKeypair::random().public().into();
+        let me: PeerId = OffchainKeypair::random().public().into();
+
+        let peers = basic_network(&me).await;
+
+        let relay_addr = Multiaddr::empty().with(Protocol::P2pCircuit);
+
+        assert_eq!(
+            peers
+                .add(&peer, PeerOrigin::IncomingConnection, vec![relay_addr.clone()], true)
+                .await
+                .unwrap(),
+            Some(NetworkTriggeredEvent::AttemptHolePunch {
+                peer,
+                relay: relay_addr
+            })
+        );
+    }
+
+    #[async_std::test]
+    async fn test_network_update_should_suggest_a_hole_punch_after_a_successful_relayed_ping() {
+        let peer: PeerId = OffchainKeypair::random().public().into();
+        let me: PeerId = OffchainKeypair::random().public().into();
+
+        let peers = basic_network(&me).await;
+
+        let relay_addr = Multiaddr::empty().with(Protocol::P2pCircuit);
+
+        peers
+            .add(&peer, PeerOrigin::IncomingConnection, vec![relay_addr.clone()], true)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            peers
+                .update(&peer, Ok(std::time::Duration::from_millis(13u64)), None)
+                .await
+                .expect("no error should occur"),
+            Some(NetworkTriggeredEvent::AttemptHolePunch {
+                peer,
+                relay: relay_addr
+            })
+        );
+    }
+
+    #[async_std::test]
+    async fn test_network_update_should_track_a_rolling_average_of_the_observed_latency() {
+        let peer: PeerId = OffchainKeypair::random().public().into();
+        let me: PeerId = OffchainKeypair::random().public().into();
+
+        let peers = basic_network(&me).await;
+
+        peers.add(&peer, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
+
+        peers
+            .update(&peer, Ok(std::time::Duration::from_millis(100)), None)
+            .await
+            .expect("no error should occur");
+
+        let after_first = peers.get(&peer).await.unwrap().unwrap();
+        assert_eq!(after_first.smoothed_latency, std::time::Duration::from_millis(100));
+
+        peers
+            .update(&peer, Ok(std::time::Duration::from_millis(200)), None)
+            .await
+            .expect("no error should occur");
+
+        let after_second = peers.get(&peer).await.unwrap().unwrap();
+        // the EWMA should move towards the new sample without jumping straight to it
+        assert!(after_second.smoothed_latency > std::time::Duration::from_millis(100));
+        assert!(after_second.smoothed_latency < std::time::Duration::from_millis(200));
+    }
+
+    #[async_std::test]
+    async fn test_network_update_should_not_reward_a_successful_ping_above_the_latency_penalty_threshold() {
+        let peer: PeerId = OffchainKeypair::random().public().into();
+        let me: PeerId = OffchainKeypair::random().public().into();
+
+        let mut cfg = NetworkConfig::default();
+        cfg.quality_offline_threshold = 0.6;
+        cfg.reputation_banned_threshold = i32::MIN;
+        cfg.latency_penalty_threshold = std::time::Duration::from_millis(50);
+
+        let peers = Network::new(
+            me,
+            vec![],
+            cfg,
+            hopr_db_sql::db::HoprDb::new_in_memory(ChainKeypair::random()).await,
+        );
+
+        peers.add(&peer, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
+
+        let quality_before = peers.get(&peer).await.unwrap().unwrap().get_quality();
+
+        // a successful ping that is slower than the configured threshold should not move the
+        // peer's quality, unlike a successful ping below it
+        peers
+            .update(&peer, Ok(std::time::Duration::from_millis(500)), None)
+            .await
+            .expect("no error should occur");
+
+        let quality_after_slow_ping = peers.get(&peer).await.unwrap().unwrap().get_quality();
+        assert_eq!(quality_before, quality_after_slow_ping);
+
+        peers
+            .update(&peer, Ok(std::time::Duration::from_millis(10)), None)
+            .await
+            .expect("no error should occur");
+
+        let quality_after_fast_ping = peers.get(&peer).await.unwrap().unwrap().get_quality();
+        assert!(quality_after_fast_ping > quality_after_slow_ping);
+    }
+
+    #[async_std::test]
+    async fn test_network_should_be_able_to_register_a_failed_heartbeat_result() {
+        let peer: PeerId = OffchainKeypair::random().public().into();
+        let me: PeerId = OffchainKeypair::random().public().into();
+
+        let peers = basic_network(&me).await;
+
+        peers.add(&peer, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
+
+        // Needs to do 3 pings, so we get over the ignore threshold limit
+        // when doing the 4th failed ping
+        peers
+            .update(&peer, Ok(std::time::Duration::from_millis(123_u64)), None)
+            .await
+            .expect("no error should occur");
+        peers
+            .update(&peer, Ok(std::time::Duration::from_millis(200_u64)), None)
+            .await
+            .expect("no error should occur");
+        peers
+            .update(&peer, Ok(std::time::Duration::from_millis(200_u64)), None)
+            .await
+            .expect("no error should occur");
+
+        peers.update(&peer, Err(()), None).await.expect("no error should occur");
+
+        let actual = peers
+            .get(&peer)
+            .await
+            .unwrap()
+            .expect("the peer record should be present");
+
+        assert_eq!(actual.heartbeats_succeeded, 3);
+        assert_eq!(actual.backoff, 300f64);
+    }
+
+    #[async_std::test]
+    async fn test_network_peer_should_be_listed_for_the_ping_if_last_recorded_later_than_reference() {
+        let first: PeerId = OffchainKeypair::random().public().into();
+        let second: PeerId = OffchainKeypair::random().public().into();
+        let me: PeerId = OffchainKeypair::random().public().into();
+
+        let peers = basic_network(&me).await;
+
+        peers.add(&first, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
+        peers
+            .add(&second, PeerOrigin::IncomingConnection, vec![], true)
             .await
             .unwrap();
 
@@ -668,7 +1685,7 @@ mod tests {
 
         let peers = basic_network(&me).await;
 
-        peers.add(&peer, PeerOrigin::IncomingConnection, vec![]).await.unwrap();
+        peers.add(&peer, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
 
         // all peers are public
         assert_eq!(peers.health().await, Health::Orange);
@@ -681,7 +1698,7 @@ mod tests {
 
         let peers = basic_network(&me).await;
 
-        peers.add(&peer, PeerOrigin::IncomingConnection, vec![]).await.unwrap();
+        peers.add(&peer, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
         let _ = peers.health();
         peers.remove(&peer).await.expect("should not fail on DB remove");
 
@@ -703,7 +1720,7 @@ mod tests {
             hopr_db_sql::db::HoprDb::new_in_memory(ChainKeypair::random()).await,
         );
 
-        peers.add(&peer, PeerOrigin::IncomingConnection, vec![]).await.unwrap();
+        peers.add(&peer, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
 
         peers
             .update(&peer, Ok(current_time().as_unix_timestamp()), None)
@@ -714,13 +1731,14 @@ mod tests {
     }
 
     #[async_std::test]
-    async fn test_network_should_close_connection_to_peer_once_it_reaches_the_lowest_possible_quality() {
+    async fn test_network_should_ban_peer_once_it_reaches_the_lowest_possible_quality() {
         let peer: PeerId = OffchainKeypair::random().public().into();
         let public = peer;
         let me: PeerId = OffchainKeypair::random().public().into();
 
         let mut cfg = NetworkConfig::default();
         cfg.quality_offline_threshold = 0.6;
+        cfg.reputation_banned_threshold = -1;
 
         let peers = Network::new(
             me,
@@ -729,7 +1747,7 @@ mod tests {
             hopr_db_sql::db::HoprDb::new_in_memory(ChainKeypair::random()).await,
         );
 
-        peers.add(&peer, PeerOrigin::IncomingConnection, vec![]).await.unwrap();
+        peers.add(&peer, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
 
         assert_eq!(
             peers
@@ -738,12 +1756,337 @@ mod tests {
                 .expect("no error should occur"),
             Some(NetworkTriggeredEvent::UpdateQuality(peer.clone(), 0.1))
         );
+
+        let Some(NetworkTriggeredEvent::Ban(banned_peer, _)) =
+            peers.update(&peer, Err(()), None).await.expect("no error should occur")
+        else {
+            panic!("expected a Ban event once the peer crossed the banned threshold");
+        };
+        assert_eq!(banned_peer, peer);
+
+        assert!(peers.is_banned(&peer).await.expect("should not fail on DB read"));
+        assert!(peers.has(&public).await);
+
+        // re-admitting a banned peer must be refused until the ban expires
+        assert!(peers.add(&peer, PeerOrigin::IncomingConnection, vec![], true).await.is_err());
+    }
+
+    #[async_std::test]
+    async fn test_network_should_keep_the_connection_open_while_reputation_decays_above_the_banned_threshold() {
+        let peer: PeerId = OffchainKeypair::random().public().into();
+        let me: PeerId = OffchainKeypair::random().public().into();
+
+        let mut cfg = NetworkConfig::default();
+        cfg.quality_offline_threshold = 0.6;
+        cfg.reputation_reward = 10;
+        cfg.reputation_penalty = 1;
+        cfg.reputation_decay_divisor = 1_000_000;
+        cfg.reputation_banned_threshold = -100;
+
+        let peers = Network::new(
+            me,
+            vec![],
+            cfg,
+            hopr_db_sql::db::HoprDb::new_in_memory(ChainKeypair::random()).await,
+        );
+
+        peers.add(&peer, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
+
+        peers
+            .update(&peer, Ok(std::time::Duration::from_millis(13u64)), None)
+            .await
+            .expect("no error should occur");
+
+        // a single failed ping should not be enough to cross a lenient banned_threshold
         assert_eq!(
             peers.update(&peer, Err(()), None).await.expect("no error should occur"),
-            Some(NetworkTriggeredEvent::CloseConnection(peer))
+            Some(NetworkTriggeredEvent::UpdateQuality(peer, 0.0))
         );
+        assert!(peers.has(&peer).await);
+    }
 
-        assert!(peers.has(&public).await);
+    #[async_std::test]
+    async fn test_network_reputation_should_reflect_rewards_and_penalties() {
+        let peer: PeerId = OffchainKeypair::random().public().into();
+        let me: PeerId = OffchainKeypair::random().public().into();
+
+        let mut cfg = NetworkConfig::default();
+        cfg.quality_offline_threshold = 0.6;
+        cfg.reputation_reward = 10;
+        cfg.reputation_penalty = 3;
+        cfg.reputation_decay_divisor = 1_000_000;
+
+        let peers = Network::new(
+            me,
+            vec![],
+            cfg,
+            hopr_db_sql::db::HoprDb::new_in_memory(ChainKeypair::random()).await,
+        );
+
+        assert_eq!(peers.reputation(&peer).await.expect("should not fail on DB read"), None);
+
+        peers.add(&peer, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
+
+        peers
+            .update(&peer, Ok(std::time::Duration::from_millis(13u64)), None)
+            .await
+            .expect("no error should occur");
+        assert_eq!(peers.reputation(&peer).await.expect("should not fail on DB read"), Some(10));
+
+        peers.update(&peer, Err(()), None).await.expect("no error should occur");
+        assert_eq!(peers.reputation(&peer).await.expect("should not fail on DB read"), Some(7));
+
+        assert!(!peers.is_banned(&peer).await.expect("should not fail on DB read"));
+    }
+
+    #[async_std::test]
+    async fn test_network_sample_shareable_peers_should_only_return_verified_high_quality_peers() {
+        let good: PeerId = OffchainKeypair::random().public().into();
+        let unverified: PeerId = OffchainKeypair::random().public().into();
+        let me: PeerId = OffchainKeypair::random().public().into();
+
+        let mut cfg = NetworkConfig::default();
+        cfg.quality_offline_threshold = 0.6;
+        cfg.pex_quality_threshold = 0.05;
+        cfg.client_mode_quality_threshold = 0.0;
+
+        let peers = Network::new(
+            me,
+            vec![],
+            cfg,
+            hopr_db_sql::db::HoprDb::new_in_memory(ChainKeypair::random()).await,
+        );
+
+        peers.add(&good, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
+        peers
+            .update(&good, Ok(std::time::Duration::from_millis(10u64)), None)
+            .await
+            .expect("no error should occur");
+
+        // never pinged, so still below `pex_quality_threshold` and unverified
+        peers.add(&unverified, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
+
+        let shared = peers.sample_shareable_peers(10).await.expect("should not fail on DB read");
+
+        assert_eq!(shared.len(), 1);
+        assert_eq!(shared[0].id.1, good);
+    }
+
+    #[async_std::test]
+    async fn test_network_is_client_mode_should_demote_a_peer_whose_quality_falls_below_the_threshold() {
+        let peer: PeerId = OffchainKeypair::random().public().into();
+        let me: PeerId = OffchainKeypair::random().public().into();
+
+        let mut cfg = NetworkConfig::default();
+        cfg.quality_offline_threshold = 0.6;
+        cfg.client_mode_quality_threshold = 0.5;
+
+        let peers = Network::new(
+            me,
+            vec![],
+            cfg,
+            hopr_db_sql::db::HoprDb::new_in_memory(ChainKeypair::random()).await,
+        );
+
+        peers.add(&peer, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
+
+        // a freshly added, never-pinged peer starts at the lowest quality, so it's client mode
+        assert_eq!(peers.is_client_mode(&peer).await.expect("should not fail on DB read"), Some(true));
+
+        assert_eq!(peers.is_client_mode(&PeerId::random()).await.expect("should not fail on DB read"), None);
+    }
+
+    #[async_std::test]
+    async fn test_network_ingest_exchanged_peers_should_add_new_peers_at_conservative_quality() {
+        let source: PeerId = OffchainKeypair::random().public().into();
+        let discovered: PeerId = OffchainKeypair::random().public().into();
+        let me: PeerId = OffchainKeypair::random().public().into();
+
+        let mut cfg = NetworkConfig::default();
+        cfg.quality_offline_threshold = 0.6;
+        cfg.pex_max_peers_per_source_per_interval = 10;
+        cfg.pex_rate_limit_interval = std::time::Duration::from_secs(60);
+
+        let peers = Network::new(
+            me,
+            vec![],
+            cfg,
+            hopr_db_sql::db::HoprDb::new_in_memory(ChainKeypair::random()).await,
+        );
+
+        let ingested = peers
+            .ingest_exchanged_peers(&source, vec![(discovered, vec![])])
+            .await
+            .expect("no error should occur");
+
+        assert_eq!(ingested, 1);
+        assert!(peers.has(&discovered).await);
+
+        let stored = peers.get(&discovered).await.unwrap().unwrap();
+        assert_eq!(stored.get_quality(), 0.0);
+    }
+
+    #[async_std::test]
+    async fn test_network_ingest_exchanged_peers_should_rate_limit_a_single_source() {
+        let source: PeerId = OffchainKeypair::random().public().into();
+        let me: PeerId = OffchainKeypair::random().public().into();
+
+        let mut cfg = NetworkConfig::default();
+        cfg.quality_offline_threshold = 0.6;
+        cfg.pex_max_peers_per_source_per_interval = 1;
+        cfg.pex_rate_limit_interval = std::time::Duration::from_secs(60);
+
+        let peers = Network::new(
+            me,
+            vec![],
+            cfg,
+            hopr_db_sql::db::HoprDb::new_in_memory(ChainKeypair::random()).await,
+        );
+
+        let discovered: Vec<(PeerId, Vec<Multiaddr>)> = (0..3)
+            .map(|_| (OffchainKeypair::random().public().into(), vec![]))
+            .collect();
+
+        let ingested = peers
+            .ingest_exchanged_peers(&source, discovered)
+            .await
+            .expect("no error should occur");
+
+        assert_eq!(ingested, 1);
+    }
+
+    #[async_std::test]
+    async fn test_network_update_should_grow_the_reconnect_delay_on_consecutive_failures() {
+        let peer: PeerId = OffchainKeypair::random().public().into();
+        let me: PeerId = OffchainKeypair::random().public().into();
+
+        let mut cfg = NetworkConfig::default();
+        cfg.quality_offline_threshold = 0.6;
+        cfg.reputation_banned_threshold = i32::MIN;
+        cfg.reconnect_base_delay = std::time::Duration::from_secs(1);
+        cfg.reconnect_max_delay = std::time::Duration::from_secs(100);
+        cfg.reconnect_backoff_factor = 2.0;
+
+        let peers = Network::new(
+            me,
+            vec![],
+            cfg,
+            hopr_db_sql::db::HoprDb::new_in_memory(ChainKeypair::random()).await,
+        );
+
+        peers.add(&peer, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
+        assert_eq!(peers.next_reconnect_at(&peer).await.unwrap(), None);
+
+        peers.update(&peer, Err(()), None).await.expect("no error should occur");
+        let after_first = peers.get(&peer).await.unwrap().unwrap();
+        assert_eq!(after_first.reconnect_delay, std::time::Duration::from_secs(1));
+        assert_eq!(after_first.reconnect_attempts, 1);
+        assert!(peers.next_reconnect_at(&peer).await.unwrap().is_some());
+
+        peers.update(&peer, Err(()), None).await.expect("no error should occur");
+        let after_second = peers.get(&peer).await.unwrap().unwrap();
+        assert_eq!(after_second.reconnect_delay, std::time::Duration::from_secs(2));
+        assert_eq!(after_second.reconnect_attempts, 2);
+
+        // a subsequent success resets the backoff entirely
+        peers
+            .update(&peer, Ok(std::time::Duration::from_millis(10)), None)
+            .await
+            .expect("no error should occur");
+        let after_success = peers.get(&peer).await.unwrap().unwrap();
+        assert_eq!(after_success.reconnect_delay, std::time::Duration::from_secs(1));
+        assert_eq!(after_success.reconnect_attempts, 0);
+        assert_eq!(peers.next_reconnect_at(&peer).await.unwrap(), None);
+    }
+
+    #[async_std::test]
+    async fn test_network_find_peers_to_reconnect_should_only_surface_peers_whose_backoff_elapsed() {
+        let peer: PeerId = OffchainKeypair::random().public().into();
+        let me: PeerId = OffchainKeypair::random().public().into();
+
+        let mut cfg = NetworkConfig::default();
+        cfg.quality_offline_threshold = 0.6;
+        cfg.reputation_banned_threshold = i32::MIN;
+        cfg.reconnect_base_delay = std::time::Duration::from_secs(1);
+        cfg.reconnect_max_delay = std::time::Duration::from_secs(100);
+        cfg.reconnect_backoff_factor = 2.0;
+
+        let peers = Network::new(
+            me,
+            vec![],
+            cfg,
+            hopr_db_sql::db::HoprDb::new_in_memory(ChainKeypair::random()).await,
+        );
+
+        peers.add(&peer, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
+        peers.update(&peer, Err(()), None).await.expect("no error should occur");
+
+        let not_yet_due = peers.find_peers_to_reconnect(current_time()).await.unwrap();
+        assert!(!not_yet_due.contains(&NetworkTriggeredEvent::AttemptReconnect(peer)));
+
+        let now_due = peers
+            .find_peers_to_reconnect(current_time() + std::time::Duration::from_secs(10))
+            .await
+            .unwrap();
+        assert!(now_due.contains(&NetworkTriggeredEvent::AttemptReconnect(peer)));
+    }
+
+    #[async_std::test]
+    async fn test_network_latency_weighted_quality_should_favor_consistently_fast_peers() {
+        let fast: PeerId = OffchainKeypair::random().public().into();
+        let slow: PeerId = OffchainKeypair::random().public().into();
+        let me: PeerId = OffchainKeypair::random().public().into();
+
+        let mut cfg = NetworkConfig::default();
+        cfg.quality_offline_threshold = 0.6;
+        cfg.quality_alpha = 0.5;
+        cfg.quality_max_good_latency = std::time::Duration::from_millis(200);
+
+        let peers = Network::new(
+            me,
+            vec![],
+            cfg,
+            hopr_db_sql::db::HoprDb::new_in_memory(ChainKeypair::random()).await,
+        );
+
+        assert_eq!(
+            peers.latency_weighted_quality(&fast).await.expect("should not fail on DB read"),
+            None
+        );
+
+        peers.add(&fast, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
+        peers.add(&slow, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
+
+        peers
+            .update(&fast, Ok(std::time::Duration::from_millis(10)), None)
+            .await
+            .expect("no error should occur");
+        peers
+            .update(&slow, Ok(std::time::Duration::from_millis(190)), None)
+            .await
+            .expect("no error should occur");
+
+        let fast_score = peers
+            .latency_weighted_quality(&fast)
+            .await
+            .expect("should not fail on DB read")
+            .expect("peer should be present");
+        let slow_score = peers
+            .latency_weighted_quality(&slow)
+            .await
+            .expect("should not fail on DB read")
+            .expect("peer should be present");
+
+        assert!(fast_score > slow_score);
+
+        // a failed ping should decay the score back towards zero
+        peers.update(&fast, Err(()), None).await.expect("no error should occur");
+        let fast_score_after_failure = peers
+            .latency_weighted_quality(&fast)
+            .await
+            .expect("should not fail on DB read")
+            .expect("peer should be present");
+        assert!(fast_score_after_failure < fast_score);
     }
 
     #[async_std::test]
@@ -761,7 +2104,7 @@ mod tests {
             hopr_db_sql::db::HoprDb::new_in_memory(ChainKeypair::random()).await,
         );
 
-        peers.add(&peer, PeerOrigin::IncomingConnection, vec![]).await.unwrap();
+        peers.add(&peer, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
 
         for _ in 0..3 {
             peers
@@ -789,8 +2132,8 @@ mod tests {
             hopr_db_sql::db::HoprDb::new_in_memory(ChainKeypair::random()).await,
         );
 
-        peers.add(&peer, PeerOrigin::IncomingConnection, vec![]).await.unwrap();
-        peers.add(&peer2, PeerOrigin::IncomingConnection, vec![]).await.unwrap();
+        peers.add(&peer, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
+        peers.add(&peer2, PeerOrigin::IncomingConnection, vec![], true).await.unwrap();
 
         for _ in 0..3 {
             peers